@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+use bevy_debug_grid::*;
+
+mod default_cube;
+
+/**
+ * This example demonstrates `GridAntialiasing`, with MSAA disabled on the camera.
+ *
+ * The floor grid is drawn with anti-aliased lines, press space to toggle between them and hardware lines.
+ */
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            default_cube::CameraControllerPlugin::default(),
+            DebugGridPlugin::without_floor_grid(),
+        ))
+        .add_systems(Startup, spawn_demonstration_objects)
+        .add_systems(Update, (disable_msaa, toggle_antialiasing))
+        .run();
+}
+
+fn spawn_demonstration_objects(mut commands: Commands) {
+    commands.spawn((
+        Grid {
+            spacing: 10.0_f32,
+            count: 16,
+            ..default()
+        },
+        SubGrid::default(),
+        GridAxis::new_rgb(),
+        TrackedGrid::default(),
+        GridAntialiasing::default(),
+        Transform::default(),
+        Visibility::default(),
+    ));
+
+    commands.spawn((
+        PointLight::default(),
+        Transform::from_xyz(4.0_f32, 4.0_f32, 4.0_f32),
+    ));
+}
+
+fn disable_msaa(mut query: Query<&mut Msaa, With<Camera3d>>) {
+    for mut msaa in query.iter_mut() {
+        msaa.set_if_neq(Msaa::Off);
+    }
+}
+
+fn toggle_antialiasing(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut query: Query<(Entity, &mut Grid, Has<GridAntialiasing>)>,
+) {
+    if !keyboard.just_pressed(KeyCode::Space) {
+        return;
+    }
+    for (entity, mut grid, antialiased) in query.iter_mut() {
+        if antialiased {
+            commands.entity(entity).remove::<GridAntialiasing>();
+            // Removing a component does not remesh the grid, unlike changing it
+            grid.set_changed();
+        } else {
+            commands.entity(entity).insert(GridAntialiasing::default());
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use bevy::{color::palettes::tailwind, prelude::*};
+use bevy_debug_grid::*;
+use std::collections::VecDeque;
+
+mod default_cube;
+
+/**
+ * This example demonstrates filling individual cells of a grid with a `GridCellOverlay`.
+ *
+ * A few cells are filled when the grid is spawned, and a trail of cells moves around the cube,
+ * filling cells ahead of it and clearing cells behind it.
+ */
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            default_cube::CameraControllerPlugin::default(),
+            DebugGridPlugin::without_floor_grid(),
+        ))
+        .add_systems(Startup, spawn_grid)
+        .add_systems(Update, move_trail)
+        .run();
+}
+
+/// Cells currently filled by the trail, from the oldest to the newest
+#[derive(Component, Default)]
+struct Trail(VecDeque<IVec2>);
+
+const TRAIL_LENGTH: usize = 12;
+
+fn spawn_grid(mut commands: Commands) {
+    let blocked = Color::Srgba(tailwind::RED_500.with_alpha(0.4_f32));
+    commands.spawn((
+        Grid {
+            spacing: 1.0_f32,
+            count: 8,
+            ..default()
+        },
+        GridAxis::new_rgb(),
+        [
+            IVec2::new(-1, -1),
+            IVec2::new(-1, 0),
+            IVec2::new(0, -1),
+            IVec2::new(0, 0),
+        ]
+        .into_iter()
+        .map(|cell| (cell, blocked))
+        .collect::<GridCellOverlay>(),
+        Trail::default(),
+        Transform::default(),
+        Visibility::default(),
+    ));
+}
+
+fn move_trail(time: Res<Time>, mut query: Query<(&mut GridCellOverlay, &mut Trail)>) {
+    let angle = time.elapsed_secs();
+    let head = (Vec2::new(angle.cos(), angle.sin()) * 4.0_f32)
+        .floor()
+        .as_ivec2();
+    for (mut overlay, mut trail) in query.iter_mut() {
+        if trail.0.back() == Some(&head) {
+            continue;
+        }
+        trail.0.push_back(head);
+        if trail.0.len() > TRAIL_LENGTH {
+            if let Some(tail) = trail.0.pop_front() {
+                overlay.remove(tail);
+            }
+        }
+        for (index, cell) in trail.0.iter().enumerate() {
+            let alpha = (index + 1) as f32 / TRAIL_LENGTH as f32 * 0.6_f32;
+            overlay.insert(*cell, Color::Srgba(tailwind::SKY_400.with_alpha(alpha)));
+        }
+    }
+}
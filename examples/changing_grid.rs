@@ -85,6 +85,12 @@ fn spawn_demonstration_objects(mut commands: Commands) {
         SubGrid {
             count: 4,
             color: Color::Srgba(tailwind::VIOLET_500),
+            line_style: LineStyle::Dashed {
+                dash: 0.05_f32,
+                gap: 0.05_f32,
+                units: LineUnits::World,
+            },
+            ..default()
         },
         GridChangePeriod(period),
         GridChangingSubCount { min: 0, max: 3 },
@@ -111,6 +117,7 @@ fn spawn_demonstration_objects(mut commands: Commands) {
         SubGrid {
             count: 3,
             color: Color::WHITE,
+            ..default()
         },
         GridChangePeriod(period),
         GridChangingSubColor,
@@ -21,7 +21,8 @@ struct ChangingAxis;
 fn spawn_demonstration_grid(mut commands: Commands) {
     commands.spawn((
         Grid::default(),
-        GridAxis::new_empty(),
+        // Arrowheads point at the positive end of the colored axis, with its negative half dimmed
+        GridAxis::new_empty().with_markers(),
         ChangingAxis,
         Transform::default(),
         Visibility::default(),
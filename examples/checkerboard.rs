@@ -0,0 +1,91 @@
+use bevy::{color::palettes::tailwind, prelude::*};
+use bevy_debug_grid::*;
+
+mod default_cube;
+
+/**
+ * This example demonstrates `GridFill::Checkerboard`.
+ *
+ * The tracked floor grid is filled underneath its lines, its checkerboard staying in place while the camera moves.
+ * The vertical grid behind the cube is filled with translucent cells instead of lines.
+ * The camera has a `DistanceFog`, which covers the floor grid through its `GridShading`.
+ */
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            default_cube::CameraControllerPlugin::default(),
+            DebugGridPlugin::without_floor_grid(),
+        ))
+        .add_systems(Startup, spawn_demonstration_objects)
+        .add_systems(Update, add_distance_fog)
+        .run();
+}
+
+fn spawn_demonstration_objects(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.spawn((
+        Grid {
+            spacing: 1.0_f32,
+            count: 16,
+            ..default()
+        },
+        GridFill::checkerboard(
+            Color::Srgba(tailwind::GRAY_700),
+            Color::Srgba(tailwind::GRAY_800),
+        ),
+        GridAxis::new_rgb(),
+        TrackedGrid::default(),
+        GridShading::default(),
+        Transform::default(),
+        Visibility::default(),
+    ));
+
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(1.0_f32, 1.0_f32, 1.0_f32))),
+        MeshMaterial3d(materials.add(StandardMaterial::from(Color::WHITE))),
+        Transform::from_xyz(0.0_f32, 0.5_f32, 0.0_f32),
+    ));
+    // Vertical grid, behind the cube
+    commands.spawn((
+        Grid {
+            spacing: 0.25_f32,
+            count: 8,
+            ..default()
+        },
+        GridFill::Checkerboard {
+            colors: [
+                Color::Srgba(tailwind::AMBER_400.with_alpha(0.6_f32)),
+                Color::Srgba(tailwind::SKY_400.with_alpha(0.6_f32)),
+            ],
+            lines: false,
+        },
+        Transform::from_xyz(0.0_f32, 2.0_f32, -2.0_f32)
+            .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+        Visibility::default(),
+    ));
+
+    commands.spawn((
+        PointLight::default(),
+        Transform::from_xyz(4.0_f32, 4.0_f32, 4.0_f32),
+    ));
+}
+
+fn add_distance_fog(
+    mut commands: Commands,
+    query: Query<Entity, (With<Camera3d>, Without<DistanceFog>)>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).insert(DistanceFog {
+            color: Color::srgb(0.25_f32, 0.25_f32, 0.3_f32),
+            falloff: FogFalloff::Linear {
+                start: 5.0_f32,
+                end: 15.0_f32,
+            },
+            ..default()
+        });
+    }
+}
@@ -50,6 +50,13 @@ fn spawn_floor_grid(mut commands: Commands) {
             ..default()
         },
         GridAxis::new_empty(),
+        // Fades out over a longer distance, and keeps lines seen at grazing angles
+        GridFade {
+            start: Some(20.0_f32),
+            curve: FadeCurve::Linear,
+            grazing_attenuation: 0.5_f32,
+            ..default()
+        },
         TrackedGrid {
             alignment: GridAlignment::X,
             ..default()
@@ -0,0 +1,56 @@
+use bevy::{color::palettes::tailwind, prelude::*};
+use bevy_debug_grid::*;
+
+mod default_cube;
+
+/**
+ * This example demonstrates grids drawn through gizmos instead of meshes.
+ *
+ * The floor grid is drawn with gizmos since the plugin is added `with_gizmos()`.
+ * The line width of every gizmo grid is configured through the `DebugGridGizmos` config group.
+ */
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            default_cube::CameraControllerPlugin::default(),
+            DebugGridPlugin::with_floor_grid().with_gizmos(),
+        ))
+        .add_systems(Startup, (spawn_cube, configure_gizmos))
+        .run();
+}
+
+fn spawn_cube(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(1.0_f32, 1.0_f32, 1.0_f32))),
+        MeshMaterial3d(materials.add(StandardMaterial::from(Color::WHITE))),
+        Transform::from_xyz(0.0_f32, 0.5_f32, 0.0_f32),
+    ));
+
+    // A small grid standing next to the cube
+    commands.spawn((
+        Grid {
+            color: Color::Srgba(tailwind::ORANGE_500),
+            ..default()
+        },
+        GridAxis::new_rgb(),
+        GizmoGrid,
+        Transform::from_xyz(-1.5_f32, 1.0_f32, 0.0_f32)
+            .with_rotation(Quat::from_rotation_x(90.0_f32.to_radians())),
+        Visibility::default(),
+    ));
+
+    commands.spawn((
+        PointLight::default(),
+        Transform::from_xyz(4.0_f32, 4.0_f32, 4.0_f32),
+    ));
+}
+
+fn configure_gizmos(mut config_store: ResMut<GizmoConfigStore>) {
+    let (config, _) = config_store.config_mut::<gizmos::DebugGridGizmos>();
+    config.line.width = 1.0_f32;
+}
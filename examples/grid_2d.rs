@@ -0,0 +1,81 @@
+use bevy::{color::palettes::tailwind, input::mouse::MouseWheel, prelude::*};
+use bevy_debug_grid::*;
+
+/**
+ * This example demonstrates a tracked 2D grid, rendered behind sprites.
+ *
+ * Use the `W` `A` `S` `D` keys to move the camera, and the mouse wheel to zoom.
+ * The grid adapts its line count to the camera's orthographic scale.
+ * Press tab to cycle the grid between lines, dots, and crosses.
+ */
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, DebugGrid2dPlugin::with_floor_grid_2d()))
+        .add_systems(Startup, setup)
+        .add_systems(Update, (move_camera, zoom_camera, cycle_grid_style))
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    // Sprites sit in front of the grid, at the default depth of 0.0
+    for (index, color) in [tailwind::ORANGE_500, tailwind::CYAN_500, tailwind::LIME_500]
+        .into_iter()
+        .enumerate()
+    {
+        commands.spawn((
+            Sprite::from_color(color, Vec2::splat(80.0_f32)),
+            Transform::from_xyz(index as f32 * 150.0_f32 - 150.0_f32, 50.0_f32, 0.0_f32),
+        ));
+    }
+}
+
+fn move_camera(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    camera: Single<(&mut Transform, &Projection), With<Camera2d>>,
+) {
+    let axis_motion_intent = |positive: KeyCode, negative: KeyCode| {
+        (keys.pressed(positive) as i8 - keys.pressed(negative) as i8) as f32
+    };
+    let (mut transform, projection) = camera.into_inner();
+    let Projection::Orthographic(projection) = projection else {
+        return;
+    };
+    let translation_intent = Vec2::new(
+        axis_motion_intent(KeyCode::KeyD, KeyCode::KeyA),
+        axis_motion_intent(KeyCode::KeyW, KeyCode::KeyS),
+    )
+    .normalize_or_zero();
+    transform.translation +=
+        (translation_intent * 400.0_f32 * projection.scale * time.delta_secs()).extend(0.0_f32);
+}
+
+fn zoom_camera(
+    mut wheel: MessageReader<MouseWheel>,
+    mut projection: Single<&mut Projection, With<Camera2d>>,
+) {
+    let delta = wheel.read().map(|wheel| wheel.y).sum::<f32>();
+    if let Projection::Orthographic(projection) = projection.as_mut() {
+        projection.scale =
+            (projection.scale * (1.0_f32 - delta * 0.1_f32)).clamp(0.1_f32, 20.0_f32);
+    }
+}
+
+fn cycle_grid_style(keys: Res<ButtonInput<KeyCode>>, mut query: Query<&mut Grid>) {
+    if !keys.just_pressed(KeyCode::Tab) {
+        return;
+    }
+    for mut grid in query.iter_mut() {
+        // Markers are sized relative to the sub-grid's cells
+        let size = grid.spacing * 0.04_f32;
+        grid.style = match grid.style {
+            GridStyle::Lines => GridStyle::Dots { size },
+            GridStyle::Dots { .. } => GridStyle::Crosses {
+                size: size * 2.0_f32,
+            },
+            GridStyle::Crosses { .. } => GridStyle::Lines,
+        };
+    }
+}
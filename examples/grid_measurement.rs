@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+use bevy_debug_grid::*;
+
+mod default_cube;
+
+/**
+ * This example demonstrates measuring distances on the plane of a grid with `GridMeasurement`.
+ *
+ * A measurement between two pillars is shown, snapped to the sub-grid.
+ * Release the cursor with `Esc`, and click twice on the floor grid to measure between both clicks.
+ */
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            default_cube::CameraControllerPlugin::default(),
+            DebugGridPlugin::without_floor_grid(),
+        ))
+        .add_systems(Startup, spawn_scene)
+        .run();
+}
+
+fn spawn_scene(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let grid = commands
+        .spawn((
+            Grid {
+                spacing: 1.0_f32,
+                count: 16,
+                ..default()
+            },
+            SubGrid {
+                count: 3,
+                ..default()
+            },
+            GridAxis::new_rgb(),
+            TrackedGrid::default(),
+            Transform::default(),
+            Visibility::default(),
+        ))
+        .id();
+
+    // Pillars
+    let pillars = [
+        Vec3::new(-3.0_f32, 0.0_f32, -2.0_f32),
+        Vec3::new(2.5_f32, 0.0_f32, 3.25_f32),
+    ];
+    let mesh = meshes.add(Cylinder::new(0.25_f32, 2.0_f32));
+    let material = materials.add(StandardMaterial::from(Color::WHITE));
+    for pillar in pillars {
+        commands.spawn((
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(material.clone()),
+            Transform::from_translation(pillar + Vec3::Y),
+        ));
+    }
+    commands.spawn(GridMeasurement {
+        interactive: false,
+        ..GridMeasurement::new(grid, pillars[0], pillars[1])
+    });
+
+    // Measurement set by clicking on the grid
+    commands.spawn(GridMeasurement::interactive(grid));
+
+    commands.spawn((
+        PointLight::default(),
+        Transform::from_xyz(4.0_f32, 4.0_f32, 4.0_f32),
+    ));
+}
@@ -0,0 +1,77 @@
+use bevy::core_pipeline::prepass::DepthPrepass;
+use bevy::prelude::*;
+use bevy_debug_grid::*;
+
+mod default_cube;
+
+/**
+ * This example demonstrates `GridOcclusion`, which needs a `DepthPrepass` on the camera.
+ *
+ * The floor grid fades out softly where it meets the half-buried sphere,
+ * and the vertical grid passing through the cube is drawn dashed and dimmed where the cube hides it.
+ */
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            default_cube::CameraControllerPlugin::default(),
+            DebugGridPlugin::without_floor_grid(),
+        ))
+        .add_systems(Startup, spawn_demonstration_objects)
+        .add_systems(Update, add_depth_prepass)
+        .run();
+}
+
+fn spawn_demonstration_objects(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.spawn((
+        Grid::default(),
+        SubGrid::default(),
+        GridAxis::new_rgb(),
+        TrackedGrid::default(),
+        GridOcclusion::soft(0.3_f32),
+        Transform::default(),
+        Visibility::default(),
+    ));
+
+    commands.spawn((
+        Mesh3d(meshes.add(Sphere::new(1.0_f32))),
+        MeshMaterial3d(materials.add(StandardMaterial::from(Color::WHITE))),
+        Transform::from_xyz(-2.0_f32, 0.25_f32, 0.0_f32),
+    ));
+
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(1.0_f32, 1.0_f32, 1.0_f32))),
+        MeshMaterial3d(materials.add(StandardMaterial::from(Color::WHITE))),
+        Transform::from_xyz(1.0_f32, 0.5_f32, 0.0_f32),
+    ));
+    // Vertical grid, passing through the cube
+    commands.spawn((
+        Grid {
+            spacing: 0.25_f32,
+            count: 8,
+            ..default()
+        },
+        GridOcclusion::x_ray(),
+        Transform::from_xyz(1.0_f32, 1.0_f32, 0.0_f32)
+            .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+        Visibility::default(),
+    ));
+
+    commands.spawn((
+        PointLight::default(),
+        Transform::from_xyz(4.0_f32, 4.0_f32, 4.0_f32),
+    ));
+}
+
+fn add_depth_prepass(
+    mut commands: Commands,
+    query: Query<Entity, (With<Camera3d>, Without<DepthPrepass>)>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).insert(DepthPrepass);
+    }
+}
@@ -0,0 +1,95 @@
+use bevy::{color::palettes::tailwind, prelude::*};
+use bevy_debug_grid::*;
+
+mod default_cube;
+
+/**
+ * This example demonstrates entities occupying the cells of a grid with a `GridOccupant`.
+ *
+ * Cubes wander across the grid, every cell they enter and exit is logged,
+ * and cells occupied by at least one cube are filled through a `GridCellOverlay`.
+ */
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            default_cube::CameraControllerPlugin::default(),
+            DebugGridPlugin::without_floor_grid(),
+        ))
+        .add_systems(Startup, spawn_grid_and_cubes)
+        .add_systems(Update, (move_cubes, show_occupied_cells))
+        .run();
+}
+
+/// Speed and radius of a cube's orbit around the origin
+#[derive(Component)]
+struct Wander(f32, f32);
+
+fn spawn_grid_and_cubes(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let grid = commands
+        .spawn((
+            Grid {
+                spacing: 1.0_f32,
+                count: 8,
+                ..default()
+            },
+            GridAxis::new_rgb(),
+            GridCellOverlay::new(),
+            Transform::default(),
+            Visibility::default(),
+        ))
+        .id();
+
+    let mesh = meshes.add(Cuboid::new(0.5_f32, 0.5_f32, 0.5_f32));
+    let material = materials.add(StandardMaterial::from(Color::WHITE));
+    for (speed, radius) in [(0.8_f32, 2.5_f32), (-0.5_f32, 4.0_f32), (0.3_f32, 6.0_f32)] {
+        commands.spawn((
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(material.clone()),
+            Transform::default(),
+            GridOccupant::new(grid),
+            Wander(speed, radius),
+        ));
+    }
+
+    commands.spawn((
+        PointLight::default(),
+        Transform::from_xyz(4.0_f32, 4.0_f32, 4.0_f32),
+    ));
+}
+
+fn move_cubes(time: Res<Time>, mut query: Query<(&mut Transform, &Wander)>) {
+    for (mut transform, Wander(speed, radius)) in query.iter_mut() {
+        let angle = time.elapsed_secs() * speed;
+        transform.translation = Vec3::new(angle.cos() * radius, 0.25_f32, angle.sin() * radius);
+    }
+}
+
+fn show_occupied_cells(
+    mut exited: MessageReader<CellExited>,
+    mut entered: MessageReader<CellEntered>,
+    occupancy: Res<GridOccupancy>,
+    mut query: Query<&mut GridCellOverlay>,
+) {
+    for exit in exited.read() {
+        info!("{} exited cell {}", exit.occupant, exit.cell);
+        if let Ok(mut overlay) = query.get_mut(exit.grid) {
+            if !occupancy.is_occupied(exit.grid, exit.cell) {
+                overlay.remove(exit.cell);
+            }
+        }
+    }
+    for enter in entered.read() {
+        info!("{} entered cell {}", enter.occupant, enter.cell);
+        if let Ok(mut overlay) = query.get_mut(enter.grid) {
+            overlay.insert(
+                enter.cell,
+                Color::Srgba(tailwind::EMERALD_400.with_alpha(0.4_f32)),
+            );
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+use bevy_debug_grid::*;
+
+mod default_cube;
+
+/**
+ * This example demonstrates the picking backend reporting hits on the plane of grids.
+ *
+ * Release the cursor with `Esc`, and click on the floor grid.
+ * The clicked cell and the snapped position are logged by an observer on the grid entity.
+ */
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            default_cube::CameraControllerPlugin::default(),
+            DebugGridPlugin::without_floor_grid(),
+        ))
+        .add_systems(Startup, spawn_floor_grid)
+        .run();
+}
+
+fn spawn_floor_grid(mut commands: Commands) {
+    commands
+        .spawn((
+            Grid {
+                spacing: 1.0_f32,
+                count: 16,
+                ..default()
+            },
+            SubGrid::default(),
+            GridAxis::new_rgb(),
+            TrackedGrid::default(),
+            Transform::default(),
+            Visibility::default(),
+        ))
+        .observe(log_clicked_cell);
+}
+
+fn log_clicked_cell(click: On<Pointer<Click>>, query_hits: Query<&GridPickHits>) {
+    let Some(hit) = query_hits
+        .get(click.entity)
+        .ok()
+        .and_then(|hits| hits.get(click.pointer_id))
+    else {
+        return;
+    };
+    info!(
+        "Clicked cell {}, snapped to {}",
+        hit.cell, hit.snapped_to_sub_grid
+    );
+}
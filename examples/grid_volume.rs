@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+use bevy_debug_grid::*;
+
+mod default_cube;
+
+/**
+ * This example demonstrates `GridVolume`, filling bounds with a 3D lattice of lines.
+ *
+ * The lattice of the sphere fits the `Aabb` of its mesh, and the other one is given explicit bounds,
+ * which grow and shrink over time.
+ */
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            default_cube::CameraControllerPlugin::default(),
+            DebugGridPlugin::with_floor_grid(),
+        ))
+        .add_systems(Startup, spawn_volumes)
+        .add_systems(Update, resize_volume)
+        .run();
+}
+
+fn spawn_volumes(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.spawn((
+        Mesh3d(meshes.add(Sphere::new(1.0_f32))),
+        MeshMaterial3d(materials.add(StandardMaterial::from(Color::WHITE))),
+        Transform::from_xyz(-2.0_f32, 1.0_f32, 0.0_f32),
+        GridVolume::from_aabb(0.25_f32),
+    ));
+
+    commands.spawn((
+        GridVolume::from_min_max(Vec3::ZERO, Vec3::ONE, 0.5_f32),
+        Transform::from_xyz(1.0_f32, 0.0_f32, -1.0_f32),
+        Visibility::default(),
+    ));
+
+    commands.spawn((
+        PointLight::default(),
+        Transform::from_xyz(4.0_f32, 4.0_f32, 4.0_f32),
+    ));
+}
+
+fn resize_volume(time: Res<Time>, mut query: Query<&mut GridVolume>) {
+    let size = 1.5_f32 + time.elapsed_secs().sin();
+    for mut volume in query.iter_mut() {
+        // Only explicit bounds are resized, leaving the sphere's volume unchanged
+        if let VolumeBounds::MinMax { min, .. } = volume.bounds {
+            volume.bounds = VolumeBounds::MinMax {
+                min,
+                max: Vec3::new(size, 2.0_f32, 2.0_f32),
+            };
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use bevy::prelude::*;
+use bevy_debug_grid::*;
+
+mod default_cube;
+
+/**
+ * This example demonstrates a `GridHeatmap` colored from a scalar field, below the lines of a grid.
+ *
+ * Two sources of influence orbit the cube, and the heatmap is updated every frame by mutating its values.
+ * The grid on the left uses nearest sampling with the magma colormap,
+ * while the grid on the right uses smooth sampling with a diverging colormap.
+ */
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            default_cube::CameraControllerPlugin::default(),
+            DebugGridPlugin::without_floor_grid(),
+        ))
+        .add_systems(Startup, spawn_grids)
+        .add_systems(Update, update_heatmaps)
+        .run();
+}
+
+const SIZE: u32 = 16;
+
+fn spawn_grids(mut commands: Commands) {
+    for (x, colormap, sampling, range) in [
+        (
+            -9.0_f32,
+            HeatmapColormap::Magma,
+            HeatmapSampling::Nearest,
+            (0.0_f32, 1.0_f32),
+        ),
+        (
+            9.0_f32,
+            HeatmapColormap::Diverging,
+            HeatmapSampling::Smooth,
+            (-1.0_f32, 1.0_f32),
+        ),
+    ] {
+        commands.spawn((
+            Grid {
+                spacing: 1.0_f32,
+                count: (SIZE / 2) as usize,
+                ..default()
+            },
+            GridHeatmap {
+                origin: IVec2::splat(-(SIZE as i32) / 2),
+                colormap,
+                sampling,
+                range,
+                ..GridHeatmap::new(vec![0.0_f32; (SIZE * SIZE) as usize], UVec2::splat(SIZE))
+            },
+            Transform::from_xyz(x, 0.0_f32, 0.0_f32),
+            Visibility::default(),
+        ));
+    }
+}
+
+fn update_heatmaps(time: Res<Time>, mut query: Query<&mut GridHeatmap>) {
+    let angle = time.elapsed_secs();
+    let sources = [
+        Vec2::new(angle.cos(), angle.sin()) * 4.0_f32,
+        Vec2::new((angle * 0.7_f32).sin(), (angle * 0.7_f32).cos()) * -3.0_f32,
+    ];
+    for mut heatmap in query.iter_mut() {
+        let diverging = heatmap.range.0 < 0.0_f32;
+        let origin = heatmap.origin;
+        for y in 0..SIZE as i32 {
+            for x in 0..SIZE as i32 {
+                let cell = origin + IVec2::new(x, y);
+                let center = cell.as_vec2() + Vec2::splat(0.5_f32);
+                let [first, second] =
+                    sources.map(|source| (-center.distance_squared(source) / 8.0_f32).exp());
+                let value = if diverging {
+                    first - second
+                } else {
+                    first + second
+                };
+                heatmap.set(cell, value);
+            }
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use bevy::prelude::*;
+use bevy_debug_grid::*;
+
+mod default_cube;
+
+/**
+ * This example demonstrates the grid cell under the cursor being published and highlighted.
+ *
+ * Release the cursor with `Esc`, and hover the floor grid to outline the cell under the cursor.
+ * Every time the hovered cell changes, it is logged through the `HoveredGridCell` message.
+ */
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            default_cube::CameraControllerPlugin::default(),
+            DebugGridPlugin::without_floor_grid(),
+        ))
+        .add_systems(Startup, spawn_floor_grid)
+        .add_systems(Update, log_hovered_cell)
+        .run();
+}
+
+fn spawn_floor_grid(mut commands: Commands) {
+    commands.spawn((
+        Grid {
+            spacing: 1.0_f32,
+            count: 16,
+            ..default()
+        },
+        SubGrid::default(),
+        GridAxis::new_rgb(),
+        TrackedGrid::default(),
+        GridCellHighlight::default(),
+        Transform::default(),
+        Visibility::default(),
+    ));
+}
+
+fn log_hovered_cell(mut reader: MessageReader<HoveredGridCell>) {
+    for hovered in reader.read() {
+        info!(
+            "Hovering cell {} of grid {} at {}",
+            hovered.cell, hovered.grid, hovered.world
+        );
+    }
+}
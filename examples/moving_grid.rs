@@ -88,6 +88,11 @@ fn spawn_demonstration_objects(
                     color: Color::Srgba(tailwind::CYAN_500),
                     ..default()
                 },
+                // Clipped to a circle fading out around the grid's own origin
+                GridFade {
+                    center: FadeCenter::GridOrigin,
+                    ..default()
+                },
                 Moving { origin: Vec3::ZERO },
             ));
         });
@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+use bevy_debug_grid::*;
+
+mod default_cube;
+use default_cube::{camera_bundle, CameraControllerPlugin};
+
+/**
+ * This example demonstrates an `OrientationGizmo`, an axis triad showing the camera's orientation
+ * in the bottom-right corner of the viewport.
+ *
+ * Clicking the end of an axis snaps the camera onto it, looking back at origin.
+ * The triad is rendered by an overlay camera, which the floor grid is not tracked to.
+ */
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            CameraControllerPlugin::without_camera(),
+            DebugGridPlugin::with_floor_grid(),
+        ))
+        .add_systems(Startup, spawn_camera_and_cube)
+        .add_systems(Update, log_snapped_views)
+        .run();
+}
+
+fn spawn_camera_and_cube(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.spawn((
+        camera_bundle(CameraControllerPlugin::default_transform()),
+        OrientationGizmo::default(),
+    ));
+
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(1.0_f32, 1.0_f32, 1.0_f32))),
+        MeshMaterial3d(materials.add(StandardMaterial::from(Color::WHITE))),
+        Transform::from_xyz(0.0_f32, 0.5_f32, 0.0_f32),
+    ));
+
+    commands.spawn((
+        PointLight::default(),
+        Transform::from_xyz(4.0_f32, 4.0_f32, 4.0_f32),
+    ));
+}
+
+fn log_snapped_views(mut snapped: MessageReader<OrientationSnapped>) {
+    for snap in snapped.read() {
+        info!("Camera {} now looks from {:?}", snap.camera, snap.direction);
+    }
+}
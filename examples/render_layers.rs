@@ -136,6 +136,7 @@ fn setup(
         SubGrid {
             count: 9,
             color: Color::WHITE.with_alpha(Grid::DEFAULT_ALPHA),
+            ..default()
         },
         TrackedGrid {
             // It is tracked to the secondary camera entity instead of the entity containing a Spectator component
@@ -0,0 +1,62 @@
+use bevy::color::palettes::tailwind;
+use bevy::prelude::*;
+use bevy_debug_grid::*;
+
+mod default_cube;
+
+/// Height between stacked floor grids
+const STOREY_HEIGHT: f32 = 3.0_f32;
+
+/**
+ * This example demonstrates `GridHeightColor`, telling grids stacked at different heights apart.
+ *
+ * Tracked floor grids are stacked like the storeys of a building, each colored from a palette by its offset,
+ * and the horizontal lines of a lattice are colored by their height along a gradient.
+ */
+fn main() {
+    App::new()
+        .add_plugins((
+            DefaultPlugins,
+            default_cube::CameraControllerPlugin::default(),
+            DebugGridPlugin::without_floor_grid(),
+        ))
+        .add_systems(Startup, spawn_grids)
+        .run();
+}
+
+fn spawn_grids(mut commands: Commands) {
+    // One color per storey, cycling every four storeys
+    let palette = GridHeightColor::palette(
+        [
+            tailwind::SKY_400,
+            tailwind::EMERALD_400,
+            tailwind::AMBER_400,
+            tailwind::ROSE_400,
+        ]
+        .map(Color::Srgba),
+        STOREY_HEIGHT,
+    );
+    for storey in 0..4 {
+        commands.spawn((
+            Grid::default(),
+            SubGrid::default(),
+            TrackedGrid {
+                offset: storey as f32 * STOREY_HEIGHT,
+                ..default()
+            },
+            palette.clone(),
+            Transform::default(),
+            Visibility::default(),
+        ));
+    }
+
+    commands.spawn((
+        GridVolume::from_min_max(Vec3::ZERO, Vec3::new(2.0_f32, 9.0_f32, 2.0_f32), 1.0_f32),
+        GridHeightColor::gradient([
+            (0.0_f32, Color::Srgba(tailwind::BLUE_500)),
+            (9.0_f32, Color::Srgba(tailwind::RED_500)),
+        ]),
+        Transform::from_xyz(2.0_f32, 0.0_f32, -4.0_f32),
+        Visibility::default(),
+    ));
+}
@@ -0,0 +1,315 @@
+#![allow(clippy::type_complexity)]
+
+use bevy::prelude::*;
+
+use crate::*;
+
+/// Gizmo config group used to draw grids marked with `GizmoGrid`.
+/// Its line width, depth bias, and render layers can be configured through the `GizmoConfigStore`.
+#[derive(Default, Reflect, GizmoConfigGroup)]
+pub struct DebugGridGizmos;
+
+/// Distance under which a tracked grid's center line is considered to lie on an axis
+const AXIS_TOLERANCE: f32 = 0.001_f32;
+
+/// Maximum count of segments a line is split into, on either side of the fade's center
+const MAX_FADE_SEGMENTS: f32 = 8.0_f32;
+
+/// Returns the line list drawing the vertices of a grid's markers or lines,
+/// filled markers being drawn as the spokes and outline of their triangles
+fn gizmo_line_list(vertices: Vec<Vec3>, style: GridStyle) -> Vec<Vec3> {
+    if !style.is_filled() {
+        return vertices;
+    }
+    vertices
+        .chunks_exact(3)
+        .flat_map(|triangle| [triangle[0], triangle[1], triangle[1], triangle[2]])
+        .collect()
+}
+
+/// Returns the faded color of a grid line's point, imitating the `ClippedLineMaterial`,
+/// or the `SimpleLineMaterial` when the distance is measured in every direction with a `plane` of `Vec3::ONE`
+fn faded_color(
+    color: Color,
+    point: Vec3,
+    center: Vec3,
+    plane: Vec3,
+    radius: f32,
+    fade: Option<&GridFade>,
+) -> Color {
+    let dist_squared = ((point - center) * plane).length_squared();
+    color.with_alpha(color.alpha() * distance_fade(dist_squared, radius, fade))
+}
+
+/// Draws a line list, given as local vertices, with a single color
+pub(crate) fn draw_line_list(
+    gizmos: &mut Gizmos<DebugGridGizmos>,
+    transform: &GlobalTransform,
+    vertices: &[Vec3],
+    color: Color,
+) {
+    for line in vertices.chunks_exact(2) {
+        gizmos.line(
+            transform.transform_point(line[0]),
+            transform.transform_point(line[1]),
+            color,
+        );
+    }
+}
+
+/// Returns the range of the factors, within `0.0..=1.0`, of the points of a line within a distance of a center.
+/// The line goes from `offset` to `offset + direction`, relative to the center.
+fn line_range_within(offset: Vec3, direction: Vec3, distance: f32) -> Option<(f32, f32)> {
+    // Solves `|offset + direction * t|² = distance²` for `t`
+    let a = direction.length_squared();
+    let b = offset.dot(direction);
+    let c = offset.length_squared() - distance * distance;
+    if a < f32::EPSILON {
+        return (c <= 0.0_f32).then_some((0.0_f32, 1.0_f32));
+    }
+    let discriminant = b * b - a * c;
+    if discriminant < 0.0_f32 {
+        return None;
+    }
+    let root = discriminant.sqrt();
+    let (first, last) = (
+        ((-b - root) / a).max(0.0_f32),
+        ((-b + root) / a).min(1.0_f32),
+    );
+    (first < last).then_some((first, last))
+}
+
+/// Draws a line list, given as local vertices, fading out with the distance to a center, measured within `plane`.
+/// Lines are clipped beyond the fade's end, and only split within the fade's band for the fade to be smooth,
+/// into segments of the grid's spacing up to `MAX_FADE_SEGMENTS` on either side of the center.
+fn draw_faded_line_list(
+    gizmos: &mut Gizmos<DebugGridGizmos>,
+    transform: &GlobalTransform,
+    vertices: &[Vec3],
+    color: impl Fn(Vec3) -> Color,
+    (center, plane, spacing, radius, fade): (Vec3, Vec3, f32, f32, Option<&GridFade>),
+) {
+    let (fade_start, fade_end) = fade.copied().unwrap_or_default().distances(radius);
+    let mut factors = Vec::new();
+    for line in vertices.chunks_exact(2) {
+        let start = transform.transform_point(line[0]);
+        let end = transform.transform_point(line[1]);
+        let (offset, direction) = ((start - center) * plane, (end - start) * plane);
+        let Some((visible_start, visible_end)) = line_range_within(offset, direction, fade_end)
+        else {
+            continue;
+        };
+        let length = start.distance(end);
+        let split = |from: f32, to: f32| {
+            let segments = ((to - from) * length / spacing)
+                .ceil()
+                .clamp(1.0_f32, MAX_FADE_SEGMENTS) as usize;
+            (0..segments).map(move |segment| from.lerp(to, segment as f32 / segments as f32))
+        };
+        factors.clear();
+        match line_range_within(offset, direction, fade_start) {
+            // Points closer than the fade's start are opaque, and drawn as a single segment
+            Some((opaque_start, opaque_end)) => {
+                if opaque_start > visible_start {
+                    factors.extend(split(visible_start, opaque_start));
+                }
+                factors.push(opaque_start);
+                if visible_end > opaque_end {
+                    factors.extend(split(opaque_end, visible_end));
+                }
+            }
+            None => factors.extend(split(visible_start, visible_end)),
+        }
+        factors.push(visible_end);
+        gizmos.linestrip_gradient(factors.iter().map(|factor| {
+            let point = start.lerp(end, *factor);
+            (
+                point,
+                faded_color(color(point), point, center, plane, radius, fade),
+            )
+        }));
+    }
+}
+
+/// System drawing grids marked with `GizmoGrid`, along with their `SubGrid` and `GridAxis`.
+/// Untracked grids with a `GridFade` centered on the camera fade around the camera tracked by the component `T`.
+pub fn draw_gizmo_grids<T: Component>(
+    mut gizmos: Gizmos<DebugGridGizmos>,
+    query: Query<
+        (
+            Entity,
+            &Grid,
+            Option<&SubGrid>,
+            Option<&GridAxis>,
+            Option<&TrackedGrid>,
+            Option<&TrackedGridCount>,
+            Option<&GridFade>,
+            Option<&GridHeightColor>,
+            &GlobalTransform,
+            &InheritedVisibility,
+        ),
+        With<GizmoGrid>,
+    >,
+    query_tracked: Query<&GlobalTransform, (With<T>, With<Camera>, TrackedEntityFilter)>,
+    #[cfg(feature = "2d")] query_2d: Query<(), With<Grid2d>>,
+) {
+    let camera_translation = query_tracked
+        .single()
+        .ok()
+        .map(GlobalTransform::translation);
+    for (entity, grid, sub_grid, axis, tracked, count, fade, height_color, transform, visibility) in
+        query.iter()
+    {
+        if !visibility.get() {
+            continue;
+        }
+        #[cfg(feature = "2d")]
+        let is_2d = query_2d.contains(entity);
+        #[cfg(not(feature = "2d"))]
+        let is_2d = {
+            let _ = entity;
+            false
+        };
+        let height_color = height_color.filter(|_| !is_2d);
+        let grid = &Grid {
+            color: height_tinted(grid.color, tracked, height_color),
+            ..tracked_grid_with_count(grid, count)
+        };
+        let alignment = grid_plane_alignment(tracked, is_2d);
+
+        let (vertices, size) = main_grid_vertices_and_size(grid, &alignment);
+        let vertices = gizmo_line_list(vertices, grid.style);
+        let sub_vertices = sub_grid.map(|sub_grid| {
+            (
+                gizmo_line_list(sub_grid_vertices(grid, sub_grid, &alignment), grid.style),
+                height_tinted(sub_grid.color, tracked, height_color),
+            )
+        });
+        let axis_color =
+            |alignment: GridAlignment| axis.and_then(|axis| axis.get_by_alignment(&alignment));
+        let in_plane_axis = alignment.plane_axis();
+
+        let Some(tracked) = tracked else {
+            // Grids with a `GridFade` fade around the camera or their origin, like the `SimpleLineMaterial`,
+            // measuring the distance on the XY plane for 2D grids
+            let plane = if is_2d {
+                alignment.to_inverted_axis_vec3()
+            } else {
+                Vec3::ONE
+            };
+            let fade = fade.map(|fade| {
+                let center = match fade.center {
+                    FadeCenter::Camera => camera_translation.unwrap_or(transform.translation()),
+                    FadeCenter::GridOrigin => transform.translation(),
+                };
+                (center, plane, grid.spacing, size - grid.spacing, Some(fade))
+            });
+            let draw = |gizmos: &mut Gizmos<DebugGridGizmos>, vertices: &[Vec3], color: Color| {
+                match fade {
+                    Some(fade) => {
+                        draw_faded_line_list(gizmos, transform, vertices, |_| color, fade);
+                    }
+                    None => draw_line_list(gizmos, transform, vertices, color),
+                }
+            };
+            draw(&mut gizmos, &vertices, grid.color);
+            if let Some((sub_vertices, color)) = sub_vertices {
+                draw(&mut gizmos, &sub_vertices, color);
+            }
+            // Axis within the grid's plane fall back to the grid's color, the normal axis is only drawn when configured
+            for axis_alignment in in_plane_axis {
+                if let Some(axis) = axis.filter(|_| axis_color(axis_alignment).is_some()) {
+                    for (vertices, color) in
+                        axis.create_axis_lines(size, grid.spacing, axis_alignment)
+                    {
+                        draw(&mut gizmos, &vertices, color);
+                    }
+                } else if grid.style.is_lines() {
+                    draw(
+                        &mut gizmos,
+                        &GridAxis::create_single_axis(size, axis_alignment),
+                        grid.color,
+                    );
+                }
+            }
+            if let Some(axis) = axis {
+                let mut lines = Vec::new();
+                if !is_2d {
+                    lines.extend(axis.create_axis_lines(size, grid.spacing, alignment));
+                }
+                if let Some(color) = axis.origin {
+                    lines.push((GridAxis::create_origin_marker(grid.spacing), color));
+                }
+                for (vertices, color) in lines {
+                    draw(&mut gizmos, &vertices, color);
+                }
+            }
+            continue;
+        };
+
+        let plane = alignment.to_inverted_axis_vec3();
+        let fade = (
+            transform.translation(),
+            plane,
+            grid.spacing,
+            size - grid.spacing,
+            fade,
+        );
+        draw_faded_line_list(&mut gizmos, transform, &vertices, |_| grid.color, fade);
+        if let Some((sub_vertices, color)) = sub_vertices {
+            draw_faded_line_list(&mut gizmos, transform, &sub_vertices, |_| color, fade);
+        }
+
+        // The grid's center lines are colored as axis when they lie on one, like in the `ClippedLineMaterial`
+        let plane_offset = alignment.to_axis_vec3() * tracked.offset;
+        for axis_alignment in in_plane_axis {
+            if !grid.style.is_lines() && axis_color(axis_alignment).is_none() {
+                continue;
+            }
+            // Center lines are split at the origin, where their negative half starts
+            let [first, last] = GridAxis::create_single_axis(size, axis_alignment);
+            let along = axis_alignment.to_axis_vec3();
+            let first_dot = transform.transform_point(first).dot(along);
+            let last_dot = transform.transform_point(last).dot(along);
+            let vertices = if first_dot * last_dot < 0.0_f32 {
+                let origin = first.lerp(last, first_dot / (first_dot - last_dot));
+                vec![first, origin, origin, last]
+            } else {
+                vec![first, last]
+            };
+            let color = |point: Vec3| {
+                let relative = (point - plane_offset) * (Vec3::ONE - axis_alignment.to_axis_vec3());
+                let Some(color) = axis_color(axis_alignment)
+                    .filter(|_| relative.abs().max_element() < AXIS_TOLERANCE)
+                else {
+                    return grid.color;
+                };
+                match axis {
+                    Some(axis) if point.dot(axis_alignment.to_axis_vec3()) < 0.0_f32 => {
+                        axis.negative_color(color)
+                    }
+                    _ => color,
+                }
+            };
+            draw_faded_line_list(&mut gizmos, transform, &vertices, color, fade);
+        }
+        // The tracked axis and markers stay at origin
+        if let Some(axis) = axis {
+            let mut lines = Vec::new();
+            if !is_2d {
+                lines.extend(axis.create_axis_lines(size, grid.spacing, alignment));
+            }
+            let (_, _, _, radius, grid_fade) = fade;
+            lines.extend(tracked_axis_markers(
+                axis,
+                radius,
+                grid.spacing,
+                alignment,
+                grid_fade,
+            ));
+            for (vertices, color) in lines {
+                draw_line_list(&mut gizmos, &GlobalTransform::IDENTITY, &vertices, color);
+            }
+        }
+    }
+}
@@ -0,0 +1,479 @@
+#![allow(clippy::type_complexity)]
+
+use bevy::asset::RenderAssetUsages;
+use bevy::camera::visibility::RenderLayers;
+use bevy::image::ImageSampler;
+#[cfg(feature = "3d")]
+use bevy::light::NotShadowCaster;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, PrimitiveTopology, TextureDimension, TextureFormat};
+#[cfg(feature = "2d")]
+use bevy::sprite_render::AlphaMode2d;
+
+use crate::*;
+
+/// Depth of heatmaps below the grid's lines, in `SUB_GRID_VERTICAL_OFFSET`.
+/// Below cell overlays, so that individual cells can be highlighted over a heatmap.
+const HEATMAP_DEPTH: f32 = 3.0_f32;
+
+/// Colormap turning the normalized values of a `GridHeatmap` into colors
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HeatmapColormap {
+    /// Perceptually uniform, from dark purple to yellow
+    #[default]
+    Viridis,
+    /// Perceptually uniform, from black to pale yellow through purple and orange
+    Magma,
+    /// From blue to red through light gray, for values diverging from the middle of the range
+    Diverging,
+}
+
+impl HeatmapColormap {
+    /// Evenly spaced colors of the viridis colormap
+    const VIRIDIS: [Srgba; 9] = [
+        Srgba::rgb(0.267, 0.005, 0.329),
+        Srgba::rgb(0.279, 0.175, 0.483),
+        Srgba::rgb(0.230, 0.322, 0.546),
+        Srgba::rgb(0.173, 0.449, 0.558),
+        Srgba::rgb(0.128, 0.567, 0.551),
+        Srgba::rgb(0.158, 0.684, 0.502),
+        Srgba::rgb(0.369, 0.789, 0.383),
+        Srgba::rgb(0.678, 0.864, 0.190),
+        Srgba::rgb(0.993, 0.906, 0.144),
+    ];
+
+    /// Evenly spaced colors of the magma colormap
+    const MAGMA: [Srgba; 9] = [
+        Srgba::rgb(0.001, 0.000, 0.014),
+        Srgba::rgb(0.110, 0.065, 0.268),
+        Srgba::rgb(0.310, 0.071, 0.483),
+        Srgba::rgb(0.506, 0.145, 0.506),
+        Srgba::rgb(0.711, 0.212, 0.478),
+        Srgba::rgb(0.898, 0.314, 0.392),
+        Srgba::rgb(0.984, 0.529, 0.380),
+        Srgba::rgb(0.996, 0.761, 0.529),
+        Srgba::rgb(0.987, 0.991, 0.750),
+    ];
+
+    /// Evenly spaced colors of a blue to red diverging colormap
+    const DIVERGING: [Srgba; 7] = [
+        Srgba::rgb(0.230, 0.299, 0.754),
+        Srgba::rgb(0.436, 0.571, 0.952),
+        Srgba::rgb(0.667, 0.779, 0.993),
+        Srgba::rgb(0.865, 0.865, 0.865),
+        Srgba::rgb(0.968, 0.721, 0.612),
+        Srgba::rgb(0.906, 0.455, 0.355),
+        Srgba::rgb(0.706, 0.016, 0.150),
+    ];
+
+    /// Returns the colors of the colormap, evenly spaced from `0.0` to `1.0`
+    const fn colors(&self) -> &'static [Srgba] {
+        match self {
+            Self::Viridis => &Self::VIRIDIS,
+            Self::Magma => &Self::MAGMA,
+            Self::Diverging => &Self::DIVERGING,
+        }
+    }
+
+    /// Returns the color of a normalized value, clamped between `0.0` and `1.0`
+    pub fn sample(&self, t: f32) -> Srgba {
+        let colors = self.colors();
+        let position = t.clamp(0.0_f32, 1.0_f32) * (colors.len() - 1) as f32;
+        let index = (position.floor() as usize).min(colors.len() - 2);
+        colors[index].mix(&colors[index + 1], position - index as f32)
+    }
+}
+
+/// Sampling of a `GridHeatmap` between the centers of its cells
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HeatmapSampling {
+    /// Each cell is filled with a single color
+    #[default]
+    Nearest,
+    /// Colors are interpolated between the centers of cells
+    Smooth,
+}
+
+/// Source of the values of a `GridHeatmap`, one per cell
+#[derive(Clone, Debug)]
+pub enum HeatmapValues {
+    /// Values stored row by row, `size.x` cells along the first axis of the grid's plane,
+    /// and `size.y` cells along the second one
+    Dense { values: Vec<f32>, size: UVec2 },
+    /// Values read from the first channel of an image, one texel per cell.
+    /// The heatmap is updated whenever the image is modified.
+    Image(Handle<Image>),
+}
+
+impl Default for HeatmapValues {
+    fn default() -> Self {
+        Self::Dense {
+            values: Vec::new(),
+            size: UVec2::ZERO,
+        }
+    }
+}
+
+/// Colors the cells of a grid from a scalar field, below the grid's lines.
+/// Spawn it next to a grid for it to have effect.
+///
+/// The first value lies in the `origin` cell, and values extend along the two axis of the grid's plane
+/// (see `GridAlignment::plane_axis`), in world space for tracked grids, and in the grid's local space otherwise.
+/// Values are mapped from `range` to the colormap, values that are not a number leave their cell empty.
+///
+/// The heatmap is updated in place whenever the component is mutated.
+#[derive(Component, Clone, Debug)]
+pub struct GridHeatmap {
+    /// Value of each cell
+    pub values: HeatmapValues,
+    /// Cell of the first value
+    pub origin: IVec2,
+    /// Colormap applied to the values
+    pub colormap: HeatmapColormap,
+    /// Values mapped to the start and the end of the colormap
+    pub range: (f32, f32),
+    /// Sampling between the centers of cells
+    pub sampling: HeatmapSampling,
+    /// Opacity of the heatmap
+    pub opacity: f32,
+}
+
+impl GridHeatmap {
+    /// Creates a heatmap from values stored row by row, with the default colormap and a range from `0.0` to `1.0`
+    pub fn new(values: Vec<f32>, size: UVec2) -> Self {
+        Self {
+            values: HeatmapValues::Dense { values, size },
+            ..default()
+        }
+    }
+
+    /// Creates a heatmap reading its values from the first channel of an image
+    pub fn from_image(image: Handle<Image>) -> Self {
+        Self {
+            values: HeatmapValues::Image(image),
+            ..default()
+        }
+    }
+
+    /// Returns the value of a cell, if it is covered by dense values
+    pub fn get(&self, cell: IVec2) -> Option<f32> {
+        let HeatmapValues::Dense { values, size } = &self.values else {
+            return None;
+        };
+        let index = dense_index(cell - self.origin, *size)?;
+        values.get(index).copied()
+    }
+
+    /// Sets the value of a cell, if it is covered by dense values
+    pub fn set(&mut self, cell: IVec2, value: f32) {
+        let HeatmapValues::Dense { values, size } = &mut self.values else {
+            return;
+        };
+        if let Some(value_mut) =
+            dense_index(cell - self.origin, *size).and_then(|index| values.get_mut(index))
+        {
+            *value_mut = value;
+        }
+    }
+
+    /// Returns the color of a value, mapped from the `range` to the colormap, or `None` if it is not a number
+    pub fn color(&self, value: f32) -> Option<Srgba> {
+        if value.is_nan() {
+            return None;
+        }
+        let (min, max) = self.range;
+        let t = if max == min {
+            0.5_f32
+        } else {
+            (value - min) / (max - min)
+        };
+        Some(self.colormap.sample(t).with_alpha(self.opacity))
+    }
+
+    /// Returns the size of the heatmap, in cells, along with a function reading the value of each texel
+    fn texels<'a>(
+        &'a self,
+        images: &'a Assets<Image>,
+    ) -> Option<(UVec2, Box<dyn Fn(u32, u32) -> f32 + 'a>)> {
+        match &self.values {
+            HeatmapValues::Dense { values, size } => Some((
+                *size,
+                Box::new(move |x, y| {
+                    values
+                        .get((y * size.x + x) as usize)
+                        .copied()
+                        .unwrap_or(f32::NAN)
+                }),
+            )),
+            HeatmapValues::Image(handle) => {
+                let image = images.get(handle)?;
+                Some((
+                    image.size(),
+                    Box::new(move |x, y| {
+                        image
+                            .get_color_at(x, y)
+                            .map_or(f32::NAN, |color| match color {
+                                // Stored values are read as is, without conversion to linear space
+                                Color::Srgba(color) => color.red,
+                                color => color.to_linear().red,
+                            })
+                    }),
+                ))
+            }
+        }
+    }
+
+    /// Renders the heatmap into an image, one texel per cell
+    fn to_image(&self, images: &Assets<Image>) -> Option<Image> {
+        let (size, value) = self.texels(images)?;
+        if size.min_element() == 0 {
+            return None;
+        }
+        let data = (0..size.y)
+            .flat_map(|y| (0..size.x).map(move |x| (x, y)))
+            .flat_map(|(x, y)| {
+                self.color(value(x, y))
+                    .map_or([0; 4], |color| color.to_u8_array())
+            })
+            .collect::<Vec<_>>();
+        let mut image = Image::new(
+            Extent3d {
+                width: size.x,
+                height: size.y,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            data,
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::all(),
+        );
+        image.sampler = match self.sampling {
+            HeatmapSampling::Nearest => ImageSampler::nearest(),
+            HeatmapSampling::Smooth => ImageSampler::linear(),
+        };
+        Some(image)
+    }
+
+    /// Returns the quad covering the heatmap's cells, with its texture coordinates
+    fn to_mesh(&self, grid: &Grid, alignment: &GridAlignment, size: UVec2) -> Mesh {
+        let [first, second] = alignment.plane_axis();
+        let corners = [Vec2::ZERO, Vec2::X, Vec2::ONE, Vec2::Y];
+        let positions = corners.map(|corner| {
+            let position = (self.origin.as_vec2() + corner * size.as_vec2()) * grid.spacing;
+            (first.to_axis_vec3() * position.x + second.to_axis_vec3() * position.y).to_array()
+        });
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::all());
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions.to_vec());
+        mesh.insert_attribute(
+            Mesh::ATTRIBUTE_UV_0,
+            corners.map(|corner| corner.to_array()).to_vec(),
+        );
+        mesh.insert_attribute(
+            Mesh::ATTRIBUTE_NORMAL,
+            vec![alignment.to_axis_vec3().to_array(); 4],
+        );
+        mesh.insert_indices(bevy::mesh::Indices::U16(vec![0, 1, 2, 0, 2, 3]));
+        mesh
+    }
+}
+
+impl Default for GridHeatmap {
+    fn default() -> Self {
+        Self {
+            values: HeatmapValues::default(),
+            origin: IVec2::ZERO,
+            colormap: HeatmapColormap::default(),
+            range: (0.0_f32, 1.0_f32),
+            sampling: HeatmapSampling::default(),
+            opacity: 0.6_f32,
+        }
+    }
+}
+
+/// Returns the index of a cell relative to the origin within dense values of a given size
+fn dense_index(cell: IVec2, size: UVec2) -> Option<usize> {
+    if cell.min_element() < 0 || cell.x as u32 >= size.x || cell.y as u32 >= size.y {
+        return None;
+    }
+    Some((cell.y as u32 * size.x + cell.x as u32) as usize)
+}
+
+/// Component to determine children spawned by a `GridHeatmap`.
+/// Holds the assets updated in place when the heatmap changes.
+#[derive(Component)]
+pub struct GridHeatmapChild {
+    mesh: Handle<Mesh>,
+    image: Handle<Image>,
+}
+
+/// System flagging heatmaps as changed when the image they read their values from is modified
+pub fn grid_heatmap_image_watcher(
+    mut events: MessageReader<AssetEvent<Image>>,
+    mut query: Query<&mut GridHeatmap>,
+) {
+    for event in events.read() {
+        let (AssetEvent::Added { id } | AssetEvent::Modified { id }) = event else {
+            continue;
+        };
+        for mut heatmap in query.iter_mut() {
+            if matches!(&heatmap.values, HeatmapValues::Image(handle) if handle.id() == *id) {
+                heatmap.set_changed();
+            }
+        }
+    }
+}
+
+/// System for rendering the heatmap of grids with a `GridHeatmap`, updating its assets in place when possible
+#[allow(clippy::too_many_arguments)]
+pub fn grid_heatmap_mesher(
+    mut commands: Commands,
+    query_parent: Query<
+        (
+            Entity,
+            &Grid,
+            &GridHeatmap,
+            Option<&TrackedGrid>,
+            &Transform,
+            Option<&RenderLayers>,
+            Option<&Children>,
+        ),
+        Or<(
+            Changed<Grid>,
+            Changed<GridHeatmap>,
+            Changed<TrackedGrid>,
+            Changed<RenderLayers>,
+        )>,
+    >,
+    query_children: Query<&GridHeatmapChild>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut images: ResMut<Assets<Image>>,
+    #[cfg(feature = "3d")] mut standard_materials: ResMut<Assets<StandardMaterial>>,
+    #[cfg(feature = "2d")] mut color_materials: ResMut<Assets<ColorMaterial>>,
+    #[cfg(feature = "2d")] query_2d: Query<(), With<Grid2d>>,
+) {
+    for (entity, grid, heatmap, tracked, transform, render_layers, children) in query_parent.iter()
+    {
+        #[cfg(feature = "2d")]
+        let is_2d = query_2d.contains(entity);
+        #[cfg(not(feature = "2d"))]
+        let is_2d = false;
+        let alignment = grid_plane_alignment(tracked, is_2d);
+
+        let image = heatmap.to_image(&images);
+        let mesh = image
+            .as_ref()
+            .map(|image| heatmap.to_mesh(grid, &alignment, image.size()));
+
+        // Updates the existing child's assets, without respawning it
+        let existing = children.and_then(|children| {
+            children
+                .iter()
+                .find_map(|child| query_children.get(child).ok().map(|assets| (child, assets)))
+        });
+        if let Some((child, assets)) = existing {
+            let mut child_commands = commands.entity(child);
+            let (Some(image), Some(mesh)) = (image, mesh) else {
+                child_commands.insert(Visibility::Hidden);
+                continue;
+            };
+            if let Some(mesh_mut) = meshes.get_mut(&assets.mesh) {
+                *mesh_mut = mesh;
+            }
+            if let Some(image_mut) = images.get_mut(&assets.image) {
+                *image_mut = image;
+            }
+            child_commands.insert(Visibility::Inherited);
+            match render_layers {
+                Some(render_layers) => child_commands.insert(render_layers.clone()),
+                None => child_commands.remove::<RenderLayers>(),
+            };
+            continue;
+        }
+
+        let (Some(image), Some(mesh)) = (image, mesh) else {
+            continue;
+        };
+        let mesh = meshes.add(mesh);
+        let image = images.add(image);
+        commands.entity(entity).with_children(|children| {
+            let mut child_commands = children.spawn((
+                GridHeatmapChild {
+                    mesh: mesh.clone(),
+                    image: image.clone(),
+                },
+                Transform::from_translation(overlay_translation(
+                    grid,
+                    tracked,
+                    &alignment,
+                    transform.translation,
+                    HEATMAP_DEPTH,
+                )),
+                Visibility::default(),
+            ));
+            #[cfg(feature = "2d")]
+            if is_2d {
+                child_commands.insert((
+                    Mesh2d(mesh.clone()),
+                    MeshMaterial2d(color_materials.add(ColorMaterial {
+                        alpha_mode: AlphaMode2d::Blend,
+                        texture: Some(image.clone()),
+                        ..ColorMaterial::from_color(Color::WHITE)
+                    })),
+                ));
+            }
+            #[cfg(feature = "3d")]
+            if !is_2d {
+                child_commands.insert((
+                    Mesh3d(mesh),
+                    NotShadowCaster,
+                    MeshMaterial3d(standard_materials.add(StandardMaterial {
+                        base_color: Color::WHITE,
+                        base_color_texture: Some(image),
+                        alpha_mode: AlphaMode::Blend,
+                        unlit: true,
+                        double_sided: true,
+                        cull_mode: None,
+                        ..default()
+                    })),
+                ));
+            }
+            if let Some(render_layers) = render_layers {
+                child_commands.insert(render_layers.clone());
+            }
+        });
+    }
+}
+
+/// System keeping the heatmap of tracked grids in place while they move
+pub fn grid_heatmap_updater(
+    query_grid: Query<
+        (Entity, &Grid, Option<&TrackedGrid>, &Transform, &Children),
+        (With<GridHeatmap>, Without<GridHeatmapChild>),
+    >,
+    mut query_children: Query<&mut Transform, With<GridHeatmapChild>>,
+    #[cfg(feature = "2d")] query_2d: Query<(), With<Grid2d>>,
+) {
+    for (entity, grid, tracked, transform, children) in query_grid.iter() {
+        #[cfg(feature = "2d")]
+        let is_2d = query_2d.contains(entity);
+        #[cfg(not(feature = "2d"))]
+        let is_2d = {
+            let _ = entity;
+            false
+        };
+        let alignment = grid_plane_alignment(tracked, is_2d);
+        let translation = overlay_translation(
+            grid,
+            tracked,
+            &alignment,
+            transform.translation,
+            HEATMAP_DEPTH,
+        );
+        let mut iter = query_children.iter_many_mut(children);
+        while let Some(mut child_transform) = iter.fetch_next() {
+            if child_transform.translation != translation {
+                child_transform.translation = translation;
+            }
+        }
+    }
+}
@@ -0,0 +1,335 @@
+#![allow(clippy::type_complexity)]
+
+#[cfg(any(feature = "3d", feature = "2d"))]
+use bevy::asset::RenderAssetUsages;
+#[cfg(any(feature = "3d", feature = "2d"))]
+use bevy::camera::visibility::RenderLayers;
+#[cfg(feature = "3d")]
+use bevy::light::NotShadowCaster;
+use bevy::prelude::*;
+#[cfg(any(feature = "3d", feature = "2d"))]
+use bevy::render::render_resource::PrimitiveTopology;
+use bevy::transform::helper::TransformHelper;
+use bevy::window::PrimaryWindow;
+
+use crate::*;
+
+/// The grid cell under the cursor, as seen through the tracked camera.
+/// The resource only exists while a grid is hovered, and is also sent as a message whenever the hovered cell changes.
+///
+/// Cells are indexed along the two axis of the grid's plane (see `GridAlignment::plane_axis`),
+/// in world space for tracked grids, and in the grid's local space otherwise.
+#[derive(Resource, Message, Clone, Copy, Debug, PartialEq)]
+pub struct HoveredGridCell {
+    /// The hovered grid entity
+    pub grid: Entity,
+    /// Index of the hovered cell
+    pub cell: IVec2,
+    /// World position of the cursor on the grid's plane
+    pub world: Vec3,
+}
+
+impl HoveredGridCell {
+    /// Returns whether both hovered cells are the same cell of the same grid
+    pub fn same_cell(&self, other: &Self) -> bool {
+        self.grid == other.grid && self.cell == other.cell
+    }
+}
+
+/// Outlines the hovered cell of a grid.
+/// Spawn it next to a grid for it to have effect.
+#[derive(Component, Clone, Debug)]
+pub struct GridCellHighlight {
+    /// Outline color
+    pub color: Color,
+}
+
+impl GridCellHighlight {
+    pub const DEFAULT_SRGBA: Srgba = bevy::color::palettes::tailwind::AMBER_400;
+}
+
+impl Default for GridCellHighlight {
+    fn default() -> Self {
+        Self {
+            color: Color::Srgba(Self::DEFAULT_SRGBA),
+        }
+    }
+}
+
+/// Marker component to determine children spawned by a `GridCellHighlight`
+#[derive(Component)]
+pub struct GridCellHighlightChild;
+
+/// Returns the local position of a cell's corner, with the lowest coordinates
+#[cfg(any(feature = "3d", feature = "2d", feature = "gizmos"))]
+fn cell_corner(grid: &Grid, cell: IVec2, alignment: &GridAlignment, cell_offset: IVec2) -> Vec3 {
+    let [first, second] = alignment.plane_axis();
+    let corner = (cell - cell_offset).as_vec2() * grid.spacing;
+    first.to_axis_vec3() * corner.x + second.to_axis_vec3() * corner.y
+}
+
+/// Returns the line list outlining a cell at the local origin
+#[cfg(any(feature = "3d", feature = "2d", feature = "gizmos"))]
+fn cell_outline(grid: &Grid, alignment: &GridAlignment) -> [Vec3; 8] {
+    let [first, second] = alignment.plane_axis();
+    // Lifted above the grid's lines to avoid z-fighting, like the sub-grid is below them
+    let lift = alignment.to_axis_vec3() * -SUB_GRID_VERTICAL_OFFSET;
+    let corners = [Vec2::ZERO, Vec2::X, Vec2::ONE, Vec2::Y].map(|corner| {
+        (first.to_axis_vec3() * corner.x + second.to_axis_vec3() * corner.y) * grid.spacing + lift
+    });
+    [
+        corners[0], corners[1], corners[1], corners[2], corners[2], corners[3], corners[3],
+        corners[0],
+    ]
+}
+
+/// System casting a ray from the cursor through the camera tracked by the component `T`,
+/// updating the `HoveredGridCell` with the nearest visible grid hit within its extent.
+/// Does nothing if the query's `.single()` fails.
+#[allow(clippy::too_many_arguments)]
+pub fn hovered_grid_cell_updater<T: Component>(
+    mut commands: Commands,
+    hovered: Option<Res<HoveredGridCell>>,
+    mut writer: MessageWriter<HoveredGridCell>,
+    query_window: Query<&Window, With<PrimaryWindow>>,
+    query_camera: Query<(Entity, &Camera, Option<&Projection>), (With<T>, TrackedEntityFilter)>,
+    query_grid: Query<(
+        Entity,
+        &Grid,
+        Option<&TrackedGrid>,
+        Option<&TrackedGridCount>,
+        &InheritedVisibility,
+    )>,
+    #[cfg(feature = "2d")] query_2d: Query<(), With<Grid2d>>,
+    transform_helper: TransformHelper,
+) {
+    let Ok((camera_entity, camera, projection)) = query_camera.single() else {
+        return;
+    };
+    let ray = query_window
+        .single()
+        .ok()
+        .and_then(|window| window.cursor_position())
+        .zip(
+            transform_helper
+                .compute_global_transform(camera_entity)
+                .ok(),
+        )
+        .and_then(|(cursor, camera_transform)| {
+            camera.viewport_to_world(&camera_transform, cursor).ok()
+        });
+
+    let mut nearest: Option<(f32, HoveredGridCell)> = None;
+    for (entity, grid, tracked, count, visibility) in query_grid.iter() {
+        let Some(ray) = ray else {
+            break;
+        };
+        if !visibility.get() {
+            continue;
+        }
+        let Ok(transform) = transform_helper.compute_global_transform(entity) else {
+            continue;
+        };
+        #[cfg(feature = "2d")]
+        let is_2d = query_2d.contains(entity);
+        #[cfg(not(feature = "2d"))]
+        let is_2d = false;
+        let alignment = grid_plane_alignment(tracked, is_2d);
+
+        let Some((local, world, distance)) = ray_grid_intersection(
+            ray,
+            &tracked_grid_with_count(grid, count),
+            &alignment,
+            &transform,
+            matches!(projection, Some(Projection::Orthographic(_))),
+        ) else {
+            continue;
+        };
+
+        if nearest.is_some_and(|(nearest_distance, _)| nearest_distance <= distance) {
+            continue;
+        }
+        let cell = grid.cell(&alignment, local)
+            + tracked_cell_offset(grid, tracked, &alignment, transform.translation());
+        nearest = Some((
+            distance,
+            HoveredGridCell {
+                grid: entity,
+                cell,
+                world,
+            },
+        ));
+    }
+
+    match (nearest, hovered) {
+        (Some((_, new)), Some(hovered)) => {
+            if !new.same_cell(&hovered) {
+                writer.write(new);
+            }
+            if new != *hovered {
+                commands.insert_resource(new);
+            }
+        }
+        (Some((_, new)), None) => {
+            writer.write(new);
+            commands.insert_resource(new);
+        }
+        (None, Some(_)) => commands.remove_resource::<HoveredGridCell>(),
+        (None, None) => {}
+    }
+}
+
+/// System for meshing the outline of grids with a `GridCellHighlight`
+#[cfg(any(feature = "3d", feature = "2d"))]
+pub fn grid_cell_highlight_mesher(
+    mut commands: Commands,
+    query_parent: Query<
+        (
+            Entity,
+            &Grid,
+            &GridCellHighlight,
+            Option<&TrackedGrid>,
+            Option<&RenderLayers>,
+            Option<&Children>,
+        ),
+        (
+            Or<(
+                Changed<Grid>,
+                Changed<GridCellHighlight>,
+                Changed<TrackedGrid>,
+                Changed<RenderLayers>,
+            )>,
+            GizmoBackend,
+        ),
+    >,
+    query_children: Query<Entity, With<GridCellHighlightChild>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    #[cfg(feature = "3d")] mut simple_materials: ResMut<Assets<SimpleLineMaterial>>,
+    #[cfg(feature = "2d")] mut simple_materials_2d: ResMut<Assets<SimpleLineMaterial2d>>,
+    #[cfg(feature = "2d")] query_2d: Query<(), With<Grid2d>>,
+) {
+    for (entity, grid, highlight, tracked, render_layers, children) in query_parent.iter() {
+        if let Some(children) = children {
+            despawn_children_of_type(&mut commands, entity, children, &query_children);
+        }
+
+        #[cfg(feature = "2d")]
+        let is_2d = query_2d.contains(entity);
+        #[cfg(not(feature = "2d"))]
+        let is_2d = false;
+        let alignment = grid_plane_alignment(tracked, is_2d);
+        let mut mesh = Mesh::new(PrimitiveTopology::LineList, RenderAssetUsages::all());
+        mesh.insert_attribute(
+            Mesh::ATTRIBUTE_POSITION,
+            cell_outline(grid, &alignment).to_vec(),
+        );
+        let mesh = meshes.add(mesh);
+
+        commands.entity(entity).with_children(|children| {
+            let mut child_commands = children.spawn((
+                GridCellHighlightChild,
+                Transform::default(),
+                Visibility::Hidden,
+            ));
+            #[cfg(feature = "2d")]
+            if is_2d {
+                child_commands.insert((
+                    Mesh2d(mesh.clone()),
+                    MeshMaterial2d(simple_materials_2d.add(SimpleLineMaterial2d::from_color(
+                        highlight.color,
+                        alpha_mode_2d(grid.alpha_mode),
+                    ))),
+                ));
+            }
+            #[cfg(feature = "3d")]
+            if !is_2d {
+                child_commands.insert((
+                    Mesh3d(mesh),
+                    NotShadowCaster,
+                    MeshMaterial3d(simple_materials.add(SimpleLineMaterial::from_color(
+                        highlight.color,
+                        grid.alpha_mode,
+                    ))),
+                ));
+            }
+            if let Some(render_layers) = render_layers {
+                child_commands.insert(render_layers.clone());
+            }
+        });
+    }
+}
+
+/// System moving the outline of grids with a `GridCellHighlight` to their hovered cell, hiding it otherwise
+#[cfg(any(feature = "3d", feature = "2d"))]
+pub fn grid_cell_highlight_updater(
+    hovered: Option<Res<HoveredGridCell>>,
+    query_grid: Query<
+        (Entity, &Grid, Option<&TrackedGrid>, &Transform, &Children),
+        (With<GridCellHighlight>, Without<GridCellHighlightChild>),
+    >,
+    mut query_children: Query<(&mut Transform, &mut Visibility), With<GridCellHighlightChild>>,
+    #[cfg(feature = "2d")] query_2d: Query<(), With<Grid2d>>,
+) {
+    for (entity, grid, tracked, transform, children) in query_grid.iter() {
+        let hovered = hovered.as_deref().filter(|hovered| hovered.grid == entity);
+        #[cfg(feature = "2d")]
+        let is_2d = query_2d.contains(entity);
+        #[cfg(not(feature = "2d"))]
+        let is_2d = false;
+        let alignment = grid_plane_alignment(tracked, is_2d);
+
+        let mut iter = query_children.iter_many_mut(children);
+        while let Some((mut child_transform, mut visibility)) = iter.fetch_next() {
+            let Some(hovered) = hovered else {
+                visibility.set_if_neq(Visibility::Hidden);
+                continue;
+            };
+            let cell_offset = tracked_cell_offset(grid, tracked, &alignment, transform.translation);
+            child_transform.translation = cell_corner(grid, hovered.cell, &alignment, cell_offset);
+            visibility.set_if_neq(Visibility::Inherited);
+        }
+    }
+}
+
+/// System drawing the outline of grids marked with `GizmoGrid` and a `GridCellHighlight` around their hovered cell
+#[cfg(feature = "gizmos")]
+pub fn draw_gizmo_cell_highlight(
+    mut gizmos: Gizmos<DebugGridGizmos>,
+    hovered: Option<Res<HoveredGridCell>>,
+    query_grid: Query<
+        (
+            &Grid,
+            &GridCellHighlight,
+            Option<&TrackedGrid>,
+            &GlobalTransform,
+            &InheritedVisibility,
+        ),
+        With<GizmoGrid>,
+    >,
+    #[cfg(feature = "2d")] query_2d: Query<(), With<Grid2d>>,
+) {
+    let Some(hovered) = hovered else {
+        return;
+    };
+    let Ok((grid, highlight, tracked, transform, visibility)) = query_grid.get(hovered.grid) else {
+        return;
+    };
+    if !visibility.get() {
+        return;
+    }
+    #[cfg(feature = "2d")]
+    let is_2d = query_2d.contains(hovered.grid);
+    #[cfg(not(feature = "2d"))]
+    let is_2d = false;
+    let alignment = grid_plane_alignment(tracked, is_2d);
+
+    let cell_offset = tracked_cell_offset(grid, tracked, &alignment, transform.translation());
+    let corner = cell_corner(grid, hovered.cell, &alignment, cell_offset);
+    for line in cell_outline(grid, &alignment).chunks_exact(2) {
+        gizmos.line(
+            transform.transform_point(corner + line[0]),
+            transform.transform_point(corner + line[1]),
+            highlight.color,
+        );
+    }
+}
@@ -0,0 +1,511 @@
+#![allow(clippy::type_complexity)]
+
+#[cfg(any(feature = "3d", feature = "2d"))]
+use bevy::asset::RenderAssetUsages;
+#[cfg(any(feature = "3d", feature = "2d"))]
+use bevy::camera::visibility::RenderLayers;
+use bevy::color::palettes::tailwind;
+use bevy::ecs::system::ParamSet;
+#[cfg(feature = "3d")]
+use bevy::light::NotShadowCaster;
+#[cfg(any(feature = "3d", feature = "2d"))]
+use bevy::mesh::Indices;
+use bevy::platform::collections::HashSet;
+#[cfg(any(feature = "3d", feature = "2d"))]
+use bevy::render::render_resource::PrimitiveTopology;
+#[cfg(feature = "2d")]
+use bevy::sprite_render::AlphaMode2d;
+use bevy::text::{
+    ComputedTextBlock, CosmicFontSystem, FontAtlasSet, FontHinting, LineHeight, SwashCache,
+    TextBounds, TextError, TextLayoutInfo, TextPipeline,
+};
+use bevy::transform::helper::TransformHelper;
+
+use crate::*;
+
+/// Offset of labels towards the camera, relative to the spacing of their grid.
+/// Purely used to draw labels over the lines they are placed on.
+const LABEL_DEPTH_OFFSET: f32 = 0.01_f32;
+
+/// Adds coordinate labels at the major lines of a grid, along both axis within the grid's plane.
+/// Spawn it next to a grid for it to have effect.
+///
+/// Labels are world-space text meshes, children of the grid, facing the tracked camera.
+/// They are rendered by the `3d` backend, or by the `2d` backend for grids marked with `Grid2d`.
+/// They follow the snapping of tracked grids, and fade out along with the lines of tracked grids
+/// and of untracked grids with a `GridFade`.
+/// Coordinates are in world space for tracked grids, and in the grid's local space otherwise.
+#[derive(Component, Clone, Debug)]
+pub struct GridLabels {
+    /// A label is placed every `step` lines of the main grid
+    pub step: usize,
+    /// Height of the labels' text, relative to the spacing of the grid
+    pub size: f32,
+    /// Font size the labels are rasterized at, in pixels
+    pub font_size: f32,
+    /// Color of the labels
+    pub color: Color,
+    /// Decimal count of the coordinates, derived from the spacing between labels when `None`
+    pub precision: Option<usize>,
+}
+
+impl GridLabels {
+    pub const DEFAULT_SRGBA: Srgba = tailwind::GRAY_300;
+
+    /// Returns the decimal count of the coordinates for a given spacing between labels
+    pub fn precision_for(&self, spacing: f32) -> usize {
+        self.precision
+            .unwrap_or_else(|| coordinate_precision(spacing))
+    }
+}
+
+impl Default for GridLabels {
+    fn default() -> Self {
+        Self {
+            step: 1,
+            size: 0.3_f32,
+            font_size: 32.0_f32,
+            color: Color::Srgba(Self::DEFAULT_SRGBA),
+            precision: None,
+        }
+    }
+}
+
+/// A coordinate label spawned by `GridLabels`, as a child of its grid
+#[derive(Component, Clone, Debug)]
+pub struct GridLabel {
+    /// The axis along which this label is placed
+    pub axis: GridAlignment,
+    /// Index of the labeled line from the grid's center, in lines of the main grid
+    pub index: i32,
+    /// The coordinate shown by this label
+    pub text: String,
+}
+
+/// Marker component to determine the glyph meshes spawned for a `GridLabel`, one per font atlas
+#[derive(Component)]
+pub struct GridLabelGlyphs;
+
+/// System spawning the labels of grids with `GridLabels`, as children of the grid
+pub fn grid_labels_spawner(
+    mut commands: Commands,
+    query_grid: Query<
+        (
+            Entity,
+            &Grid,
+            &GridLabels,
+            Option<&TrackedGrid>,
+            Option<&TrackedGridCount>,
+            Option<&Children>,
+        ),
+        Or<(
+            Changed<Grid>,
+            Changed<GridLabels>,
+            Changed<TrackedGrid>,
+            Changed<TrackedGridCount>,
+        )>,
+    >,
+    query_labels: Query<Entity, With<GridLabel>>,
+    #[cfg(feature = "2d")] query_2d: Query<(), With<Grid2d>>,
+) {
+    for (entity, grid, labels, tracked, count, children) in query_grid.iter() {
+        if let Some(children) = children {
+            despawn_children_of_type(&mut commands, entity, children, &query_labels);
+        }
+
+        #[cfg(feature = "2d")]
+        let is_2d = query_2d.contains(entity);
+        #[cfg(not(feature = "2d"))]
+        let is_2d = false;
+        let alignment = grid_plane_alignment(tracked, is_2d);
+        let count = tracked_grid_with_count(grid, count).count as i32;
+        let step = labels.step.max(1) as i32;
+        commands.entity(entity).with_children(|children| {
+            for (axis_index, axis) in alignment.plane_axis().into_iter().enumerate() {
+                for index in (-count..=count).filter(|index| index % step == 0) {
+                    // Both axis share the label of the center
+                    if index == 0 && axis_index > 0 {
+                        continue;
+                    }
+                    children.spawn((
+                        GridLabel {
+                            axis,
+                            index,
+                            text: String::new(),
+                        },
+                        TextFont::from_font_size(labels.font_size),
+                        TextColor(labels.color),
+                        TextLayout::new_with_justify(Justify::Center),
+                        Transform::from_translation(
+                            axis.to_axis_vec3() * index as f32 * grid.spacing,
+                        ),
+                        Visibility::Hidden,
+                    ));
+                }
+            }
+        });
+    }
+}
+
+/// System placing grid labels on their grid's lines, facing the camera tracked by the component `T`,
+/// or the `tracking_override` of a `TrackedGrid`
+pub fn grid_labels_updater<T: Component>(
+    query_grid: Query<(
+        Entity,
+        &Grid,
+        &GridLabels,
+        Option<&TrackedGrid>,
+        Option<&TrackedGridCount>,
+        Option<&GridFade>,
+        &Children,
+    )>,
+    query_camera: Query<Option<&Projection>, With<Camera>>,
+    query_tracked: Query<Entity, (With<T>, With<Camera>, TrackedEntityFilter)>,
+    mut params: ParamSet<(
+        TransformHelper,
+        Query<(
+            &mut GridLabel,
+            &mut Transform,
+            &mut TextColor,
+            &mut Visibility,
+        )>,
+    )>,
+) {
+    // Grid and camera transforms are computed ahead, as the labels' transforms are written below
+    let mut placements = Vec::new();
+    for (entity, grid, labels, tracked, count, fade, children) in query_grid.iter() {
+        let camera_entity = tracked
+            .and_then(|tracked| tracked.tracking_override)
+            .filter(|entity| query_camera.contains(*entity))
+            .or_else(|| query_tracked.single().ok());
+        let transforms = camera_entity.and_then(|camera_entity| {
+            let transform_helper = params.p0();
+            let grid_transform = transform_helper.compute_global_transform(entity).ok()?;
+            let camera_transform = transform_helper
+                .compute_global_transform(camera_entity)
+                .ok()?;
+            Some((grid_transform, camera_transform, camera_entity))
+        });
+        placements.push((grid, labels, tracked, count, fade, children, transforms));
+    }
+
+    let mut query_labels = params.p1();
+    for (grid, labels, tracked, count, fade, children, transforms) in placements {
+        let mut labels_iter = query_labels.iter_many_mut(children);
+        let Some((grid_transform, camera_transform, camera_entity)) = transforms else {
+            while let Some((_, _, _, mut visibility)) = labels_iter.fetch_next() {
+                visibility.set_if_neq(Visibility::Hidden);
+            }
+            continue;
+        };
+        let projection = query_camera.get(camera_entity).ok().flatten();
+
+        // Labels face the camera, and are scaled from their font's pixels to the grid's spacing
+        let (_, grid_rotation, _) = grid_transform.to_scale_rotation_translation();
+        let rotation = grid_rotation.inverse() * camera_transform.rotation();
+        let towards_camera = rotation * Vec3::Z * LABEL_DEPTH_OFFSET * grid.spacing;
+        let scale = Vec3::splat(labels.size * grid.spacing / labels.font_size.max(1.0_f32));
+        let precision = labels.precision_for(grid.spacing * labels.step.max(1) as f32);
+
+        while let Some((mut label, mut transform, mut text_color, mut visibility)) =
+            labels_iter.fetch_next()
+        {
+            let axis = label.axis.to_axis_vec3();
+            let local_position = axis * label.index as f32 * grid.spacing;
+            let position = grid_transform.transform_point(local_position);
+
+            // Labels fade out along with the lines of tracked grids, and of untracked grids with a `GridFade`
+            let mut alpha = 1.0_f32;
+            if let Some(tracked) = tracked {
+                let grid = tracked_grid_with_count(grid, count);
+                let center = tracked_grid_center(tracked, &camera_transform, projection);
+                let dist_squared = ((center - position)
+                    * tracked.alignment.to_inverted_axis_vec3())
+                .length_squared();
+                alpha = distance_fade(
+                    dist_squared,
+                    grid.count as f32 * grid.spacing - grid.spacing,
+                    fade,
+                );
+            } else if let Some(fade) = fade {
+                // Like the `SimpleLineMaterial`, around the camera or the grid's origin
+                let center = match fade.center {
+                    FadeCenter::Camera => camera_transform.translation(),
+                    FadeCenter::GridOrigin => grid_transform.translation(),
+                };
+                alpha = distance_fade(
+                    center.distance_squared(position),
+                    grid.count as f32 * grid.spacing - grid.spacing,
+                    Some(fade),
+                );
+            }
+            visibility.set_if_neq(if alpha > 0.0_f32 {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            });
+            text_color.set_if_neq(TextColor(
+                labels.color.with_alpha(labels.color.alpha() * alpha),
+            ));
+            transform.set_if_neq(Transform {
+                translation: local_position + towards_camera,
+                rotation,
+                scale,
+            });
+
+            let coordinate = if tracked.is_some() {
+                position.dot(axis)
+            } else {
+                label.index as f32 * grid.spacing
+            };
+            let coordinate = format!("{:.*}", precision, coordinate);
+            if label.text != coordinate {
+                label.text = coordinate;
+            }
+        }
+    }
+}
+
+/// System laying out the text of grid labels whenever their coordinate or font changes
+#[allow(clippy::too_many_arguments)]
+pub fn grid_label_layout(
+    mut reprocess: Local<HashSet<Entity>>,
+    mut query_labels: Query<(
+        Entity,
+        Ref<GridLabel>,
+        Ref<TextFont>,
+        &TextLayout,
+        &mut TextLayoutInfo,
+        &mut ComputedTextBlock,
+    )>,
+    text_font_query: Query<&TextFont>,
+    fonts: Res<Assets<Font>>,
+    mut font_atlas_set: ResMut<FontAtlasSet>,
+    mut texture_atlases: ResMut<Assets<TextureAtlasLayout>>,
+    mut images: ResMut<Assets<Image>>,
+    mut text_pipeline: ResMut<TextPipeline>,
+    mut font_system: ResMut<CosmicFontSystem>,
+    mut swash_cache: ResMut<SwashCache>,
+) {
+    for (entity, label, font, layout, mut layout_info, mut computed) in query_labels.iter_mut() {
+        let changed = label.is_changed()
+            || font.is_changed()
+            || computed.needs_rerender()
+            || reprocess.remove(&entity);
+        if !changed {
+            continue;
+        }
+        // Labels are rasterized at their font size, as they are scaled in world space regardless of the window
+        let spans = std::iter::once((
+            entity,
+            0,
+            label.text.as_str(),
+            &*font,
+            Color::WHITE,
+            LineHeight::default(),
+        ));
+        let result = text_pipeline
+            .update_buffer(
+                &fonts,
+                spans,
+                layout.linebreak,
+                layout.justify,
+                TextBounds::UNBOUNDED,
+                1.0_f64,
+                &mut computed,
+                &mut font_system,
+                FontHinting::Disabled,
+            )
+            .and_then(|_| {
+                text_pipeline.update_text_layout_info(
+                    &mut layout_info,
+                    text_font_query,
+                    1.0_f64,
+                    &mut font_atlas_set,
+                    &mut texture_atlases,
+                    &mut images,
+                    &mut computed,
+                    &mut font_system,
+                    &mut swash_cache,
+                    TextBounds::UNBOUNDED,
+                    layout.justify,
+                )
+            });
+        match result {
+            Ok(()) => layout_info.scale_factor = 1.0_f32,
+            // The font is not loaded yet, the label is laid out again in the next frame
+            Err(TextError::NoSuchFont) => {
+                reprocess.insert(entity);
+            }
+            // Other errors leave the label without glyphs
+            Err(_) => {}
+        }
+    }
+}
+
+/// Returns the meshes of a label's glyphs, one per font atlas texture, centered on the label
+#[cfg(any(feature = "3d", feature = "2d"))]
+fn glyph_meshes(
+    layout_info: &TextLayoutInfo,
+    texture_atlases: &Assets<TextureAtlasLayout>,
+) -> Vec<(AssetId<Image>, Mesh)> {
+    let top_left = Vec2::new(-0.5_f32, 0.5_f32) * layout_info.size;
+    let mut quads: Vec<(AssetId<Image>, Vec<[f32; 3]>, Vec<[f32; 2]>)> = Vec::new();
+    for glyph in layout_info.glyphs.iter() {
+        let Some(atlas) = texture_atlases.get(glyph.atlas_info.texture_atlas) else {
+            continue;
+        };
+        let Some(rect) = atlas.textures.get(glyph.atlas_info.location.glyph_index) else {
+            continue;
+        };
+        let center = top_left + Vec2::new(glyph.position.x, -glyph.position.y);
+        let half_size = rect.size().as_vec2() * 0.5_f32;
+        let (uv_min, uv_max) = (
+            rect.min.as_vec2() / atlas.size.as_vec2(),
+            rect.max.as_vec2() / atlas.size.as_vec2(),
+        );
+        let index = match quads
+            .iter()
+            .position(|(texture, _, _)| *texture == glyph.atlas_info.texture)
+        {
+            Some(index) => index,
+            None => {
+                quads.push((glyph.atlas_info.texture, Vec::new(), Vec::new()));
+                quads.len() - 1
+            }
+        };
+        let (_, positions, uvs) = &mut quads[index];
+        positions.extend(
+            [
+                Vec2::new(-half_size.x, -half_size.y),
+                Vec2::new(half_size.x, -half_size.y),
+                half_size,
+                Vec2::new(-half_size.x, half_size.y),
+            ]
+            .map(|corner| (center + corner).extend(0.0_f32).to_array()),
+        );
+        uvs.extend([
+            [uv_min.x, uv_max.y],
+            [uv_max.x, uv_max.y],
+            [uv_max.x, uv_min.y],
+            [uv_min.x, uv_min.y],
+        ]);
+    }
+
+    quads
+        .into_iter()
+        .map(|(texture, positions, uvs)| {
+            let indices = (0..positions.len() as u32 / 4)
+                .flat_map(|quad| [0, 1, 2, 0, 2, 3].map(|index| quad * 4 + index))
+                .collect();
+            let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::all());
+            mesh.insert_attribute(
+                Mesh::ATTRIBUTE_NORMAL,
+                vec![[0.0_f32, 0.0, 1.0]; positions.len()],
+            );
+            mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+            mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+            mesh.insert_indices(Indices::U32(indices));
+            (texture, mesh)
+        })
+        .collect()
+}
+
+/// System meshing the glyphs of grid labels whenever their layout changes,
+/// and updating the color of their materials in place otherwise
+#[cfg(any(feature = "3d", feature = "2d"))]
+#[allow(clippy::too_many_arguments)]
+pub fn grid_label_mesher(
+    mut commands: Commands,
+    query_labels: Query<
+        (
+            Entity,
+            Ref<TextLayoutInfo>,
+            &TextColor,
+            &ChildOf,
+            Option<&Children>,
+        ),
+        (
+            With<GridLabel>,
+            Or<(Changed<TextLayoutInfo>, Changed<TextColor>)>,
+        ),
+    >,
+    query_glyphs: Query<Entity, With<GridLabelGlyphs>>,
+    query_render_layers: Query<&RenderLayers>,
+    texture_atlases: Res<Assets<TextureAtlasLayout>>,
+    mut images: ResMut<Assets<Image>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    #[cfg(feature = "3d")] mut standard_materials: ResMut<Assets<StandardMaterial>>,
+    #[cfg(feature = "3d")] query_materials_3d: Query<&MeshMaterial3d<StandardMaterial>>,
+    #[cfg(feature = "2d")] mut color_materials: ResMut<Assets<ColorMaterial>>,
+    #[cfg(feature = "2d")] query_materials_2d: Query<&MeshMaterial2d<ColorMaterial>>,
+    #[cfg(feature = "2d")] query_2d: Query<(), With<Grid2d>>,
+) {
+    for (entity, layout_info, text_color, child_of, children) in query_labels.iter() {
+        // Only the color changed, the glyphs' materials are updated in place
+        if !layout_info.is_changed() {
+            for child in children.into_iter().flatten() {
+                #[cfg(feature = "3d")]
+                if let Ok(material) = query_materials_3d.get(*child) {
+                    if let Some(material) = standard_materials.get_mut(&material.0) {
+                        material.base_color = text_color.0;
+                    }
+                }
+                #[cfg(feature = "2d")]
+                if let Ok(material) = query_materials_2d.get(*child) {
+                    if let Some(material) = color_materials.get_mut(&material.0) {
+                        material.color = text_color.0;
+                    }
+                }
+            }
+            continue;
+        }
+
+        if let Some(children) = children {
+            despawn_children_of_type(&mut commands, entity, children, &query_glyphs);
+        }
+        #[cfg(feature = "2d")]
+        let is_2d = query_2d.contains(child_of.parent());
+        #[cfg(not(feature = "2d"))]
+        let is_2d = false;
+        let render_layers = query_render_layers.get(child_of.parent()).ok();
+        for (texture, mesh) in glyph_meshes(&layout_info, &texture_atlases) {
+            let Some(texture) = images.get_strong_handle(texture) else {
+                continue;
+            };
+            let mesh = meshes.add(mesh);
+            let mut child_commands =
+                commands.spawn((GridLabelGlyphs, ChildOf(entity), Transform::default()));
+            #[cfg(feature = "2d")]
+            if is_2d {
+                child_commands.insert((
+                    Mesh2d(mesh.clone()),
+                    MeshMaterial2d(color_materials.add(ColorMaterial {
+                        alpha_mode: AlphaMode2d::Blend,
+                        texture: Some(texture.clone()),
+                        ..ColorMaterial::from_color(text_color.0)
+                    })),
+                ));
+            }
+            #[cfg(feature = "3d")]
+            if !is_2d {
+                child_commands.insert((
+                    Mesh3d(mesh),
+                    NotShadowCaster,
+                    MeshMaterial3d(standard_materials.add(StandardMaterial {
+                        base_color: text_color.0,
+                        base_color_texture: Some(texture),
+                        alpha_mode: AlphaMode::Blend,
+                        unlit: true,
+                        double_sided: true,
+                        cull_mode: None,
+                        ..default()
+                    })),
+                ));
+            }
+            if let Some(render_layers) = render_layers {
+                child_commands.insert(render_layers.clone());
+            }
+        }
+    }
+}
@@ -1,22 +1,115 @@
 use bevy::{color::palettes::tailwind, prelude::*};
 
+#[cfg(feature = "gizmos")]
+pub mod gizmos;
+#[cfg(any(feature = "3d", feature = "2d"))]
+pub mod heatmap;
+#[cfg(feature = "hover")]
+pub mod hover;
+#[cfg(feature = "labels")]
+pub mod labels;
+#[cfg(feature = "measure")]
+pub mod measure;
+pub mod occupancy;
+#[cfg(feature = "orientation")]
+pub mod orientation;
+#[cfg(any(feature = "3d", feature = "2d"))]
+pub mod overlay;
+#[cfg(feature = "picking")]
+pub mod picking;
 mod plugin;
+#[cfg(feature = "3d")]
 pub mod rendering;
+#[cfg(feature = "2d")]
+pub mod rendering_2d;
+#[cfg(feature = "rulers")]
+pub mod rulers;
+pub mod snapping;
 pub mod systems;
+#[cfg(feature = "2d")]
+pub mod systems_2d;
+#[cfg(any(feature = "3d", feature = "gizmos"))]
+pub mod volume;
 
+#[cfg(feature = "gizmos")]
+use gizmos::*;
+#[cfg(any(feature = "3d", feature = "2d"))]
+use heatmap::*;
+#[cfg(any(feature = "3d", feature = "2d"))]
+pub use heatmap::{GridHeatmap, HeatmapColormap, HeatmapSampling, HeatmapValues};
+#[cfg(feature = "hover")]
+use hover::*;
+#[cfg(feature = "hover")]
+pub use hover::{GridCellHighlight, HoveredGridCell};
+#[cfg(feature = "labels")]
+use labels::*;
+#[cfg(feature = "labels")]
+pub use labels::{GridLabel, GridLabels};
+#[cfg(feature = "measure")]
+use measure::*;
+#[cfg(feature = "measure")]
+pub use measure::{GridDistance, GridMeasurement, GridMeasurementLabel, MeasurementSnapping};
+use occupancy::*;
+pub use occupancy::{CellEntered, CellExited, GridOccupancy, GridOccupant};
+#[cfg(feature = "orientation")]
+use orientation::*;
+#[cfg(feature = "orientation")]
+pub use orientation::{OrientationGizmo, OrientationSnapped, ViewportCorner};
+#[cfg(any(feature = "3d", feature = "2d"))]
+pub use overlay::GridCellOverlay;
+#[cfg(any(feature = "3d", feature = "2d"))]
+use overlay::*;
+#[cfg(feature = "picking")]
+use picking::*;
+#[cfg(feature = "picking")]
+pub use picking::{GridPickHit, GridPickHits};
 pub use plugin::*;
+#[cfg(feature = "3d")]
 use rendering::*;
+#[cfg(feature = "2d")]
+use rendering_2d::*;
+#[cfg(feature = "rulers")]
+use rulers::*;
+#[cfg(feature = "rulers")]
+pub use rulers::{GridRuler, GridRulers, RulerEdge};
+pub use snapping::GridQuery;
 use systems::*;
+#[cfg(feature = "2d")]
+use systems_2d::*;
+#[cfg(any(feature = "3d", feature = "gizmos"))]
+use volume::*;
+#[cfg(any(feature = "3d", feature = "gizmos"))]
+pub use volume::{GridVolume, VolumeBounds};
 
 pub mod prelude {
-    pub use crate::plugin::*;
+    #[cfg(feature = "gizmos")]
+    pub use super::GizmoGrid;
+    #[cfg(feature = "2d")]
+    pub use super::Grid2d;
+    #[cfg(feature = "labels")]
+    pub use super::GridLabels;
+    #[cfg(feature = "rulers")]
+    pub use super::GridRulers;
     pub use super::{
-        Grid,
-        SubGrid,
-        GridAlignment,
-        GridAxis,
-        TrackedGrid,
+        CellEntered, CellExited, FadeCenter, FadeCurve, Grid, GridAlignment, GridAxis, GridFade,
+        GridFill, GridHeightColor, GridOccupancy, GridOccupant, GridQuery, GridStyle, LineStyle,
+        LineUnits, SubGrid, TrackedGrid, TrackedGridCount,
     };
+    #[cfg(feature = "3d")]
+    pub use super::{GridAntialiasing, GridOcclusion, GridShading};
+    #[cfg(feature = "hover")]
+    pub use super::{GridCellHighlight, HoveredGridCell};
+    #[cfg(any(feature = "3d", feature = "2d"))]
+    pub use super::{GridCellOverlay, GridHeatmap, HeatmapColormap, HeatmapSampling};
+    #[cfg(feature = "measure")]
+    pub use super::{GridMeasurement, MeasurementSnapping};
+    #[cfg(feature = "picking")]
+    pub use super::{GridPickHit, GridPickHits};
+    #[cfg(any(feature = "3d", feature = "gizmos"))]
+    pub use super::{GridVolume, VolumeBounds};
+    #[cfg(feature = "orientation")]
+    pub use super::{OrientationGizmo, OrientationSnapped, ViewportCorner};
+    pub use crate::plugin::*;
 }
 
 /// The main grid component
@@ -28,13 +121,82 @@ pub struct Grid {
     pub count: usize,
     /// Line color
     pub color: Color,
+    /// Emissive intensity multiplying the line color, above `1.0` keeping lines visible in HDR and making them glow with bloom.
+    /// Only lines rendered with 3D meshes are affected.
+    pub intensity: f32,
     /// Alpha mode
     pub alpha_mode: AlphaMode,
+    /// Pattern of the lines
+    pub line_style: LineStyle,
+    /// Shape of the grid, drawn with full lines or with markers at their intersections.
+    /// The `SubGrid` follows the same style.
+    pub style: GridStyle,
 }
 
 impl Grid {
     pub const DEFAULT_SRGBA: Srgba = tailwind::GRAY_400;
     pub const DEFAULT_ALPHA: f32 = 0.5_f32;
+
+    /// Snaps a position, relative to the grid, to the nearest line intersection within planes parallel to the grid.
+    /// The position's component along `alignment`, the grid's normal, is left untouched.
+    ///
+    /// Positions are in the grid's local space, and thus in world space for tracked grids or grids at the origin.
+    /// Use `GridQuery` to snap world positions for any grid entity.
+    pub fn snap(&self, alignment: &GridAlignment, position: Vec3) -> Vec3 {
+        snap_to_spacing(self.spacing, alignment, position)
+    }
+
+    /// Snaps a position, relative to the grid, to the nearest line intersection of the grid or its sub-grid,
+    /// within planes parallel to the grid.
+    /// The position's component along `alignment`, the grid's normal, is left untouched.
+    pub fn snap_to_sub_grid(
+        &self,
+        sub_grid: &SubGrid,
+        alignment: &GridAlignment,
+        position: Vec3,
+    ) -> Vec3 {
+        snap_to_spacing(sub_grid.spacing(self), alignment, position)
+    }
+
+    /// Returns the line intersection of the grid nearest to a position relative to the grid, on the grid's plane
+    pub fn nearest_intersection(&self, alignment: &GridAlignment, position: Vec3) -> Vec3 {
+        self.snap(alignment, position) * alignment.to_inverted_axis_vec3()
+    }
+
+    /// Returns the index of the cell containing a position relative to the grid,
+    /// along the two axis of the grid's plane (see `GridAlignment::plane_axis`)
+    pub fn cell(&self, alignment: &GridAlignment, position: Vec3) -> IVec2 {
+        let [first, second] = alignment.plane_axis();
+        IVec2::new(
+            (position.dot(first.to_axis_vec3()) / self.spacing).floor() as i32,
+            (position.dot(second.to_axis_vec3()) / self.spacing).floor() as i32,
+        )
+    }
+
+    /// Returns the point nearest to a position relative to the grid, on the grid's nearest line,
+    /// along with the axis the line is parallel to.
+    pub fn nearest_line(&self, alignment: &GridAlignment, position: Vec3) -> (GridAlignment, Vec3) {
+        let on_plane = position * alignment.to_inverted_axis_vec3();
+        let intersection = self.nearest_intersection(alignment, position);
+        let [first, second] = alignment.plane_axis();
+        // A line parallel to an axis is at a distance measured along the other axis
+        let first_distance = ((on_plane - intersection) * second.to_axis_vec3()).length();
+        let second_distance = ((on_plane - intersection) * first.to_axis_vec3()).length();
+        let axis = if first_distance <= second_distance {
+            first
+        } else {
+            second
+        };
+        let along = axis.to_axis_vec3();
+        (axis, intersection * (Vec3::ONE - along) + on_plane * along)
+    }
+}
+
+/// Snaps the components of a position within the plane of `alignment` to multiples of `spacing`
+fn snap_to_spacing(spacing: f32, alignment: &GridAlignment, position: Vec3) -> Vec3 {
+    let plane = alignment.to_inverted_axis_vec3();
+    let snapped = (position / spacing).round() * spacing;
+    snapped * plane + position * alignment.to_axis_vec3()
 }
 
 impl Default for Grid {
@@ -43,7 +205,10 @@ impl Default for Grid {
             spacing: 0.25_f32,
             count: 8,
             color: Color::Srgba(Self::DEFAULT_SRGBA.with_alpha(Self::DEFAULT_ALPHA)),
+            intensity: 1.0_f32,
             alpha_mode: AlphaMode::Blend,
+            line_style: LineStyle::Solid,
+            style: GridStyle::Lines,
         }
     }
 }
@@ -52,6 +217,11 @@ impl Default for Grid {
 #[derive(Component)]
 pub struct GridChild;
 
+/// Marker component to determine children spawned by a `GridFill`
+#[cfg(feature = "3d")]
+#[derive(Component)]
+pub struct GridFillChild;
+
 /// The sub-grid component, adds lines between the lines of a grid.
 /// Spawn it next to a grid for it to have effect.
 #[derive(Component, Clone, Debug)]
@@ -60,10 +230,19 @@ pub struct SubGrid {
     pub count: usize,
     /// Line color
     pub color: Color,
+    /// Emissive intensity multiplying the line color, like `Grid::intensity`
+    pub intensity: f32,
+    /// Pattern of the lines
+    pub line_style: LineStyle,
 }
 
 impl SubGrid {
     pub const DEFAULT_SRGBA: Srgba = tailwind::GRAY_500;
+
+    /// Returns the spacing between the lines of the sub-grid, including the main grid's lines
+    pub fn spacing(&self, grid: &Grid) -> f32 {
+        grid.spacing / (self.count + 1) as f32
+    }
 }
 
 impl Default for SubGrid {
@@ -71,6 +250,8 @@ impl Default for SubGrid {
         Self {
             count: 9,
             color: Color::Srgba(Self::DEFAULT_SRGBA.with_alpha(Grid::DEFAULT_ALPHA)),
+            intensity: 1.0_f32,
+            line_style: LineStyle::Solid,
         }
     }
 }
@@ -101,6 +282,15 @@ impl GridAlignment {
         Vec3::ONE - self.to_axis_vec3()
     }
 
+    /// Returns the two axis lying within the plane of a grid with this alignment
+    pub const fn plane_axis(&self) -> [GridAlignment; 2] {
+        match self {
+            Self::X => [Self::Y, Self::Z],
+            Self::Y => [Self::X, Self::Z],
+            Self::Z => [Self::X, Self::Y],
+        }
+    }
+
     /// Shifts/rotates a `Vec3`'s values. Default `Y` alignment does nothing.
     pub const fn shift_vec3(&self, input: Vec3) -> Vec3 {
         match self {
@@ -117,6 +307,154 @@ impl From<GridAlignment> for Vec3 {
     }
 }
 
+/// Shape of a `Grid` and of its `SubGrid`, drawn with full lines or with markers at their intersections.
+/// Sizes are in the grid's local units.
+///
+/// Grids drawn with markers only draw center lines for axis with a color of their `GridAxis`.
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
+pub enum GridStyle {
+    #[default]
+    Lines,
+    /// Filled discs of diameter `size` at each intersection.
+    /// Drawn with gizmos, which cannot fill shapes, discs are drawn as the spokes of their outline.
+    Dots { size: f32 },
+    /// Crosses with arms spanning `size` across each intersection
+    Crosses { size: f32 },
+}
+
+impl GridStyle {
+    /// Number of segments of the outline of a dot
+    pub const DOT_SEGMENTS: usize = 12;
+
+    /// Whether the grid is drawn with full lines
+    pub fn is_lines(&self) -> bool {
+        *self == Self::Lines
+    }
+
+    /// Whether the markers are filled shapes, whose vertices are a triangle list instead of a line list
+    pub fn is_filled(&self) -> bool {
+        matches!(self, Self::Dots { .. })
+    }
+
+    /// Returns the vertices of the marker drawn at an intersection, on the XZ plane.
+    /// Filled markers are a triangle list, other markers a line list, and full lines have no markers.
+    pub fn marker_vertices(&self, center: Vec3) -> Vec<Vec3> {
+        match *self {
+            Self::Lines => Vec::new(),
+            Self::Dots { size } => {
+                let outline = (0..Self::DOT_SEGMENTS)
+                    .map(|index| {
+                        let angle =
+                            index as f32 * std::f32::consts::TAU / Self::DOT_SEGMENTS as f32;
+                        center + Vec3::new(angle.cos(), 0.0_f32, angle.sin()) * size * 0.5_f32
+                    })
+                    .collect::<Vec<_>>();
+                (0..Self::DOT_SEGMENTS)
+                    .flat_map(|index| {
+                        [
+                            center,
+                            outline[(index + 1) % Self::DOT_SEGMENTS],
+                            outline[index],
+                        ]
+                    })
+                    .collect()
+            }
+            Self::Crosses { size } => {
+                let half = size * 0.5_f32;
+                vec![
+                    center - Vec3::X * half,
+                    center + Vec3::X * half,
+                    center - Vec3::Z * half,
+                    center + Vec3::Z * half,
+                ]
+            }
+        }
+    }
+}
+
+/// Fills the cells of a grid, drawn underneath its lines.
+/// Spawn it next to a grid for it to have effect.
+/// Only grids rendered with 3D meshes are filled.
+///
+/// Tracked grids count their cells from the world's origin, so that the fill stays in place while they move.
+/// The fill fades out along with the lines of tracked grids, and of grids with a `GridFade`.
+#[derive(Component, Debug, Copy, Clone, PartialEq)]
+pub enum GridFill {
+    /// Cells alternating between two colors, the cell at the positive corner of the origin using the first one.
+    /// Colors with an alpha below `1.0` blend the fill over the scene.
+    Checkerboard {
+        colors: [Color; 2],
+        /// Whether the lines of the grid and of its `SubGrid` are drawn over the fill, instead of only its `GridAxis`
+        lines: bool,
+    },
+}
+
+impl GridFill {
+    /// Creates a checkerboard alternating between two colors, underneath the grid's lines
+    pub fn checkerboard(first: Color, second: Color) -> Self {
+        Self::Checkerboard {
+            colors: [first, second],
+            lines: true,
+        }
+    }
+
+    /// Whether the lines of the grid and of its `SubGrid` are drawn over the fill
+    pub fn draws_lines(&self) -> bool {
+        match *self {
+            Self::Checkerboard { lines, .. } => lines,
+        }
+    }
+}
+
+/// Units of the lengths of a `LineStyle`
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LineUnits {
+    /// Lengths in the grid's local units, the pattern staying in place along the lines
+    #[default]
+    World,
+    /// Lengths in physical pixels, the pattern keeping its size on screen
+    Screen,
+}
+
+/// Pattern of the lines of a `Grid`, a `SubGrid`, or a `GridAxis`.
+/// Only lines rendered with 3D meshes follow their pattern, other lines are solid.
+///
+/// In world units, patterns start from the grid's center lines.
+/// Tracked grids move by whole cells, thus their pattern stays in place when its period evenly divides the grid's spacing.
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
+pub enum LineStyle {
+    #[default]
+    Solid,
+    /// Dashes of length `dash`, separated by gaps of length `gap`
+    Dashed {
+        dash: f32,
+        gap: f32,
+        units: LineUnits,
+    },
+    /// Dots a single pixel long, separated by `spacing`
+    Dotted { spacing: f32, units: LineUnits },
+}
+
+impl LineStyle {
+    /// Returns the lengths of the dashes and of the gaps between them, `(dash, gap)`.
+    /// Solid lines have no gap, and dots are dashes of length `0.0`.
+    pub fn dash_gap(&self) -> (f32, f32) {
+        match *self {
+            Self::Solid => (0.0_f32, 0.0_f32),
+            Self::Dashed { dash, gap, .. } => (dash.max(0.0_f32), gap.max(0.0_f32)),
+            Self::Dotted { spacing, .. } => (0.0_f32, spacing.max(0.0_f32)),
+        }
+    }
+
+    /// Returns the units of the style's lengths
+    pub fn units(&self) -> LineUnits {
+        match *self {
+            Self::Solid => LineUnits::default(),
+            Self::Dashed { units, .. } | Self::Dotted { units, .. } => units,
+        }
+    }
+}
+
 /// Custom color overrides for axis of a grid.
 /// Spawn it next to a grid for it to have effect.
 #[derive(Component, Clone, Debug)]
@@ -127,9 +465,22 @@ pub struct GridAxis {
     pub y: Option<Color>,
     /// Color of the Z axis
     pub z: Option<Color>,
+    /// Whether arrowheads are drawn at the positive ends of colored axis
+    pub arrowheads: bool,
+    /// Color of a small marker at origin, no marker is drawn when `None`
+    pub origin: Option<Color>,
+    /// Alpha multiplier of the negative half of colored axis, `1.0` leaving it unchanged
+    pub negative_alpha: f32,
+    /// Emissive intensity multiplying the colors of colored axis and markers, like `Grid::intensity`
+    pub intensity: f32,
+    /// Pattern of the axis lines and arrowheads
+    pub line_style: LineStyle,
 }
 
 impl GridAxis {
+    /// Length of arrowheads and radius of the origin marker, relative to the grid's spacing
+    pub const MARKER_SCALE: f32 = 0.4_f32;
+
     /// An empty grid axis, does nothing.
     /// Use for later mutation or debug.
     pub const fn new_empty() -> Self {
@@ -137,6 +488,11 @@ impl GridAxis {
             x: None,
             y: None,
             z: None,
+            arrowheads: false,
+            origin: None,
+            negative_alpha: 1.0_f32,
+            intensity: 1.0_f32,
+            line_style: LineStyle::Solid,
         }
     }
 
@@ -147,6 +503,18 @@ impl GridAxis {
             x: Some(Color::Srgba(tailwind::RED_500)),
             y: Some(Color::Srgba(tailwind::GREEN_500)),
             z: Some(Color::Srgba(tailwind::BLUE_500)),
+            ..Self::new_empty()
+        }
+    }
+
+    /// Adds arrowheads at the positive ends of colored axis, an origin marker,
+    /// and dims the negative half of colored axis, telling directions apart
+    pub const fn with_markers(self) -> Self {
+        Self {
+            arrowheads: true,
+            origin: Some(Color::WHITE),
+            negative_alpha: 0.35_f32,
+            ..self
         }
     }
 
@@ -158,6 +526,71 @@ impl GridAxis {
         ]
     }
 
+    /// Creates the line list of an arrowhead, a wireframe pyramid pointing at the positive end of an axis
+    pub fn create_arrowhead(size: f32, spacing: f32, alignment: GridAlignment) -> Vec<Vec3> {
+        let length = spacing * Self::MARKER_SCALE;
+        let tip = Vec3::new(0.0_f32, size, 0.0_f32);
+        let base = [Vec3::X, Vec3::Z, Vec3::NEG_X, Vec3::NEG_Z]
+            .map(|direction| tip + direction * length * 0.4_f32 - Vec3::Y * length);
+        let mut vertices = Vec::with_capacity(16);
+        for (index, corner) in base.iter().enumerate() {
+            vertices.extend([tip, *corner, *corner, base[(index + 1) % base.len()]]);
+        }
+        vertices
+            .into_iter()
+            .map(|vertex| alignment.shift_vec3(vertex))
+            .collect()
+    }
+
+    /// Creates the line list of the origin marker, a wireframe octahedron centered on origin
+    pub fn create_origin_marker(spacing: f32) -> Vec<Vec3> {
+        let radius = spacing * Self::MARKER_SCALE * 0.5_f32;
+        let tips = [Vec3::X, Vec3::Z, Vec3::NEG_X, Vec3::NEG_Z].map(|tip| tip * radius);
+        let mut vertices = Vec::with_capacity(24);
+        for (index, tip) in tips.iter().enumerate() {
+            vertices.extend([
+                *tip,
+                tips[(index + 1) % tips.len()],
+                *tip,
+                Vec3::Y * radius,
+                *tip,
+                Vec3::NEG_Y * radius,
+            ]);
+        }
+        vertices
+    }
+
+    /// Creates the line lists of a colored axis, along with their colors.
+    /// The positive half carries the arrowhead, and the negative half is dimmed by `negative_alpha`.
+    /// Returns an empty vector when the axis has no color.
+    pub fn create_axis_lines(
+        &self,
+        size: f32,
+        spacing: f32,
+        alignment: GridAlignment,
+    ) -> Vec<(Vec<Vec3>, Color)> {
+        let Some(color) = self.get_by_alignment(&alignment) else {
+            return Vec::new();
+        };
+        let [positive_end, negative_end] = Self::create_single_axis(size, alignment);
+        let mut positive = vec![Vec3::ZERO, positive_end];
+        let negative = vec![Vec3::ZERO, negative_end];
+        if self.arrowheads {
+            positive.extend(Self::create_arrowhead(size, spacing, alignment));
+        }
+        if self.negative_alpha == 1.0_f32 {
+            positive.extend(negative);
+            vec![(positive, color)]
+        } else {
+            vec![(positive, color), (negative, self.negative_color(color))]
+        }
+    }
+
+    /// Returns the color of the negative half of an axis, dimmed by `negative_alpha`
+    pub fn negative_color(&self, color: Color) -> Color {
+        color.with_alpha(color.alpha() * self.negative_alpha)
+    }
+
     /// Creates grid axis from the configured colors.
     /// Returns a vector of used axis with their corresponding color, as well as a vector of unused axis, `(used, unused)`.
     pub fn create_axis(&self) -> (Vec<(GridAlignment, Color)>, Vec<GridAlignment>) {
@@ -219,3 +652,470 @@ pub struct TrackedGrid {
     /// Entity to be tracked instead of the plugin's generic component
     pub tracking_override: Option<Entity>,
 }
+
+/// Line count used when meshing a tracked grid, taking precedence over `Grid::count`.
+/// Inserted and maintained by the tracking systems when the tracked entity has an orthographic projection,
+/// so that the grid keeps covering the viewport while zooming.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TrackedGridCount(pub usize);
+
+/// Colors the lines of grids by their height, telling stacked grids apart at a glance.
+/// Spawn it next to a `TrackedGrid`, coloring its main grid and `SubGrid` by its `offset`,
+/// or next to a `GridVolume`, coloring each of its lattice's lines across the Y axis by its height in the volume's local space.
+///
+/// Colors replace the colors of the lines, keeping their alpha. 2D grids are not affected.
+#[derive(Component, Clone, Debug, PartialEq)]
+pub enum GridHeightColor {
+    /// Colors interpolated between `(height, color)` stops sorted by height, and clamped beyond the first and last stops
+    Gradient(Vec<(f32, Color)>),
+    /// Colors cycled through, one per level of `spacing` height, the first color being at a height of `0.0`
+    Palette { colors: Vec<Color>, spacing: f32 },
+}
+
+impl GridHeightColor {
+    /// Colors of the default palette
+    pub const DEFAULT_PALETTE: [Srgba; 6] = [
+        tailwind::SKY_400,
+        tailwind::EMERALD_400,
+        tailwind::AMBER_400,
+        tailwind::ROSE_400,
+        tailwind::VIOLET_400,
+        tailwind::LIME_400,
+    ];
+
+    /// Creates a gradient from stops in any order
+    pub fn gradient(stops: impl IntoIterator<Item = (f32, Color)>) -> Self {
+        let mut stops = stops.into_iter().collect::<Vec<_>>();
+        stops.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        Self::Gradient(stops)
+    }
+
+    /// Creates a palette cycling through colors, one per level of `spacing` height
+    pub fn palette(colors: impl IntoIterator<Item = Color>, spacing: f32) -> Self {
+        Self::Palette {
+            colors: colors.into_iter().collect(),
+            spacing,
+        }
+    }
+
+    /// Returns the color at a height, or `None` without any color
+    pub fn color(&self, height: f32) -> Option<Color> {
+        match self {
+            Self::Gradient(stops) => {
+                let next = stops.partition_point(|(stop, _)| *stop <= height);
+                match (stops.get(next.wrapping_sub(1)), stops.get(next)) {
+                    (Some((start, first)), Some((end, second))) => {
+                        let t = (height - start) / (end - start).max(f32::EPSILON);
+                        Some(Color::Srgba(
+                            Srgba::from(*first).mix(&Srgba::from(*second), t),
+                        ))
+                    }
+                    (Some((_, color)), None) | (None, Some((_, color))) => Some(*color),
+                    (None, None) => None,
+                }
+            }
+            Self::Palette { colors, spacing } => {
+                let level = (height / spacing.max(f32::EPSILON)).round() as i64;
+                let index = level.rem_euclid(colors.len().max(1) as i64) as usize;
+                colors.get(index).copied()
+            }
+        }
+    }
+
+    /// Returns the color at a height with the alpha of `color`, or `color` itself without any color
+    pub fn tint(&self, color: Color, height: f32) -> Color {
+        self.color(height)
+            .map_or(color, |tint| tint.with_alpha(color.alpha()))
+    }
+}
+
+impl Default for GridHeightColor {
+    fn default() -> Self {
+        Self::palette(Self::DEFAULT_PALETTE.map(Color::Srgba), 1.0_f32)
+    }
+}
+
+/// Curve followed by the alpha of a grid's lines while they fade out with the distance
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FadeCurve {
+    /// Alpha decreases linearly
+    Linear,
+    /// Alpha eases in and out, with a smoothstep
+    Smooth,
+    /// Alpha drops quickly after the fade starts, then slowly reaches zero
+    Exponential,
+    /// Alpha eases in and out with a smoothstep over the squared distance, dropping later and faster than `Smooth`
+    #[default]
+    SmoothSquared,
+}
+
+impl FadeCurve {
+    /// Returns the alpha multiplier of the curve, given the fade's progress from `0.0` to `1.0`.
+    /// The progress of `SmoothSquared` is measured over the squared distance.
+    pub fn alpha(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0_f32, 1.0_f32);
+        match self {
+            Self::Linear => 1.0_f32 - t,
+            Self::Smooth | Self::SmoothSquared => 1.0_f32 - t * t * (3.0_f32 - 2.0_f32 * t),
+            Self::Exponential => (1.0_f32 - t).powi(3),
+        }
+    }
+
+    /// Index of the curve within the line shaders
+    #[cfg(any(feature = "3d", feature = "2d"))]
+    pub(crate) const fn shader_index(&self) -> u32 {
+        match self {
+            Self::Linear => 0,
+            Self::Smooth => 1,
+            Self::Exponential => 2,
+            Self::SmoothSquared => 3,
+        }
+    }
+}
+
+/// Point the distance fade of a `GridFade` is measured from
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FadeCenter {
+    /// Lines fade with the distance to the camera
+    #[default]
+    Camera,
+    /// Lines fade with the distance to the grid's origin, clipping the grid to a circle around it.
+    /// Tracked grids follow their tracked entity, and thus always fade around it.
+    GridOrigin,
+}
+
+/// Configures how the lines of a grid fade out with the distance to the camera.
+/// Spawn it next to a grid for it to have effect.
+///
+/// Tracked grids fade out by default, as if they had a default `GridFade`,
+/// and lines beyond `end` are clipped, creating the illusion of an infinite grid.
+/// Untracked grids rendered with meshes only fade out with a `GridFade`, and are not attenuated at grazing angles.
+/// They are clipped beyond `end` as well, around the camera or their own origin depending on `center`.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct GridFade {
+    /// Distance at which lines start fading out, defaults to `DEFAULT_START_RATIO` of `end` when `None`
+    pub start: Option<f32>,
+    /// Distance beyond which lines are clipped, defaults to the grid's extent minus one spacing when `None`
+    pub end: Option<f32>,
+    /// Curve followed by the fade, between `start` and `end`
+    pub curve: FadeCurve,
+    /// Point the distance is measured from, for untracked grids
+    pub center: FadeCenter,
+    /// Strength of the attenuation of lines seen at grazing angles, from `0.0` for none to `1.0`
+    pub grazing_attenuation: f32,
+    /// Whether the axis are kept unfaded at grazing angles
+    pub keep_axis_unfaded: bool,
+}
+
+impl GridFade {
+    /// Ratio of `end` at which lines start fading out by default, where the squared distance reaches 60% of `end`'s
+    pub const DEFAULT_START_RATIO: f32 = 0.774_596_7_f32;
+
+    /// Creates a fade between explicit distances
+    pub fn new(start: f32, end: f32) -> Self {
+        Self {
+            start: Some(start),
+            end: Some(end),
+            ..default()
+        }
+    }
+
+    /// Returns the distances at which the fade starts and ends, `(start, end)`,
+    /// with `radius` being the default end
+    pub fn distances(&self, radius: f32) -> (f32, f32) {
+        let end = self.end.unwrap_or(radius).max(0.0_f32);
+        let start = self
+            .start
+            .unwrap_or(end * Self::DEFAULT_START_RATIO)
+            .clamp(0.0_f32, end);
+        (start, end)
+    }
+
+    /// Returns the alpha multiplier of a point at a distance, with `radius` being the default end
+    pub fn alpha(&self, distance: f32, radius: f32) -> f32 {
+        let (start, end) = self.distances(radius);
+        if distance > end {
+            return 0.0_f32;
+        }
+        let t = match self.curve {
+            FadeCurve::SmoothSquared => {
+                (distance * distance - start * start)
+                    / (end * end - start * start).max(f32::EPSILON)
+            }
+            _ => (distance - start) / (end - start).max(f32::EPSILON),
+        };
+        self.curve.alpha(t)
+    }
+}
+
+impl Default for GridFade {
+    fn default() -> Self {
+        Self {
+            start: None,
+            end: None,
+            curve: FadeCurve::default(),
+            center: FadeCenter::default(),
+            grazing_attenuation: 1.0_f32,
+            keep_axis_unfaded: true,
+        }
+    }
+}
+
+/// Configures how the lines of a grid meet scene geometry, based on the depth prepass.
+/// Spawn it next to a grid, and add a `DepthPrepass` to the camera, for it to have effect.
+///
+/// Only grids rendered with 3D meshes are affected.
+/// Their lines and fill are blended, even with an opaque `AlphaMode`, keeping them out of the depth prepass they sample.
+#[cfg(feature = "3d")]
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct GridOcclusion {
+    /// Depth over which lines fade out in front of the geometry they intersect, `0.0` keeping a hard edge
+    pub soft_intersection: f32,
+    /// Whether lines hidden behind geometry are drawn dashed and dimmed, instead of being hidden
+    pub x_ray: bool,
+    /// Alpha multiplier of hidden lines in x-ray
+    pub x_ray_alpha: f32,
+    /// Length of the dashes of hidden lines in x-ray, and of the gaps between them, in world units
+    pub x_ray_dash: f32,
+}
+
+#[cfg(feature = "3d")]
+impl GridOcclusion {
+    /// Creates a soft intersection, fading lines out over a depth
+    pub fn soft(soft_intersection: f32) -> Self {
+        Self {
+            soft_intersection,
+            ..default()
+        }
+    }
+
+    /// Creates an x-ray occlusion, drawing hidden lines dashed and dimmed
+    pub fn x_ray() -> Self {
+        Self {
+            x_ray: true,
+            ..default()
+        }
+    }
+}
+
+#[cfg(feature = "3d")]
+impl Default for GridOcclusion {
+    fn default() -> Self {
+        Self {
+            soft_intersection: 0.25_f32,
+            x_ray: false,
+            x_ray_alpha: 0.3_f32,
+            x_ray_dash: 0.1_f32,
+        }
+    }
+}
+
+/// Draws the lines of a grid, its `SubGrid` and its `GridAxis` as anti-aliased quads instead of hardware lines.
+/// Their coverage is computed in the shaders, so lines are smooth whether or not the camera uses `Msaa`.
+///
+/// Only grids rendered with 3D meshes are affected.
+/// Edges are blended, grids with an `AlphaMode::Opaque`, `AlphaMode::Mask`, or `AlphaMode::AlphaToCoverage` use `AlphaMode::Blend` instead.
+#[cfg(feature = "3d")]
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct GridAntialiasing {
+    /// Width of the lines in physical pixels, lines thinner than a pixel being dimmed instead
+    pub line_width: f32,
+}
+
+#[cfg(feature = "3d")]
+impl Default for GridAntialiasing {
+    fn default() -> Self {
+        Self {
+            line_width: 1.0_f32,
+        }
+    }
+}
+
+/// Configures how the lines and fill of a grid blend in with the scene's shading, which they ignore by default.
+///
+/// Only grids rendered with 3D meshes are affected.
+/// `VolumetricFog` is not applied to grids, as it is rendered against the depth of the scene.
+#[cfg(feature = "3d")]
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct GridShading {
+    /// Whether the grid is covered by the view's `DistanceFog`, including its atmospheric falloff
+    pub fog: bool,
+    /// Whether the inscattering of directional lights in the `DistanceFog` lights up the fog covering the grid
+    pub fog_scattering: bool,
+    /// Weight of the view's exposure on the grid's colors, from `0.0` keeping them as is like unlit materials, to `1.0` exposing them like lights
+    pub exposure: f32,
+    /// Whether the grid is tonemapped like the scene's materials, for views tonemapping in the shader without HDR
+    pub tonemapping: bool,
+}
+
+#[cfg(feature = "3d")]
+impl GridShading {
+    /// Creates a shading keeping the grid's colors as is, like unlit materials
+    pub fn unlit() -> Self {
+        Self::default()
+    }
+
+    /// Creates a shading exposing the grid's colors like lights, for colors in physical units
+    pub fn lit() -> Self {
+        Self {
+            exposure: 1.0_f32,
+            ..default()
+        }
+    }
+}
+
+#[cfg(feature = "3d")]
+impl Default for GridShading {
+    fn default() -> Self {
+        Self {
+            fog: true,
+            fog_scattering: true,
+            exposure: 0.0_f32,
+            tonemapping: true,
+        }
+    }
+}
+
+/// Marks a grid as rendered in 2D, using `Mesh2d` and `Material2d`, on the XY plane.
+/// Its `SubGrid` and `GridAxis` are rendered in 2D as well.
+///
+/// The grid is sorted against sprites by its `Transform.z`, which is the `offset` of a `TrackedGrid`.
+/// Tracked 2D grids should use `GridAlignment::Z`.
+#[cfg(feature = "2d")]
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct Grid2d;
+
+#[cfg(feature = "2d")]
+impl Grid2d {
+    /// Default depth of 2D grids, behind sprites at the default depth of `0.0`
+    pub const DEFAULT_Z: f32 = -1.0_f32;
+}
+
+/// Marks a grid as rendered through the immediate-mode `Gizmos` instead of meshes and materials.
+/// Its `SubGrid` and `GridAxis` are drawn with gizmos as well, using the `DebugGridGizmos` config group.
+///
+/// Tracked grids drawn with gizmos fade out with the distance to their center.
+#[cfg(feature = "gizmos")]
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct GizmoGrid;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(spacing: f32) -> Grid {
+        Grid {
+            spacing,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn snap_rounds_within_plane_only() {
+        let grid = grid(2.0_f32);
+        let position = Vec3::new(2.9_f32, 1.3_f32, -3.1_f32);
+        assert_eq!(
+            grid.snap(&GridAlignment::Y, position),
+            Vec3::new(2.0_f32, 1.3_f32, -4.0_f32)
+        );
+        assert_eq!(
+            grid.snap(&GridAlignment::X, position),
+            Vec3::new(2.9_f32, 2.0_f32, -4.0_f32)
+        );
+        assert_eq!(
+            grid.snap(&GridAlignment::Z, position),
+            Vec3::new(2.0_f32, 2.0_f32, -3.1_f32)
+        );
+    }
+
+    #[test]
+    fn snap_to_sub_grid_uses_sub_grid_spacing() {
+        let grid = grid(1.0_f32);
+        let sub_grid = SubGrid {
+            count: 3,
+            ..default()
+        };
+        assert_eq!(sub_grid.spacing(&grid), 0.25_f32);
+        assert_eq!(
+            grid.snap_to_sub_grid(
+                &sub_grid,
+                &GridAlignment::Y,
+                Vec3::new(0.3_f32, 5.0_f32, -0.4_f32)
+            ),
+            Vec3::new(0.25_f32, 5.0_f32, -0.5_f32)
+        );
+    }
+
+    #[test]
+    fn nearest_intersection_lies_on_plane() {
+        let grid = grid(1.0_f32);
+        assert_eq!(
+            grid.nearest_intersection(&GridAlignment::Y, Vec3::new(0.6_f32, 3.0_f32, -0.2_f32)),
+            Vec3::new(1.0_f32, 0.0_f32, 0.0_f32)
+        );
+        assert_eq!(
+            grid.nearest_intersection(&GridAlignment::X, Vec3::new(4.0_f32, -1.6_f32, 2.4_f32)),
+            Vec3::new(0.0_f32, -2.0_f32, 2.0_f32)
+        );
+    }
+
+    #[test]
+    fn cell_floors_negative_positions() {
+        let grid = grid(2.0_f32);
+        assert_eq!(
+            grid.cell(&GridAlignment::Y, Vec3::new(0.5_f32, 0.0_f32, -0.5_f32)),
+            IVec2::new(0, -1)
+        );
+        assert_eq!(
+            grid.cell(&GridAlignment::Z, Vec3::new(-4.5_f32, 4.0_f32, 1.0_f32)),
+            IVec2::new(-3, 2)
+        );
+    }
+
+    #[test]
+    fn nearest_line_picks_closest_axis() {
+        let grid = grid(1.0_f32);
+        let (axis, point) =
+            grid.nearest_line(&GridAlignment::Y, Vec3::new(0.1_f32, 1.0_f32, 0.4_f32));
+        assert_eq!(axis, GridAlignment::Z);
+        assert_eq!(point, Vec3::new(0.0_f32, 0.0_f32, 0.4_f32));
+    }
+
+    #[test]
+    fn fade_distances_default_to_radius() {
+        let (start, end) = GridFade::default().distances(10.0_f32);
+        assert_eq!(end, 10.0_f32);
+        assert!((start * start - 0.6_f32 * end * end).abs() < 1e-4_f32);
+        assert_eq!(
+            GridFade::new(20.0_f32, 5.0_f32).distances(10.0_f32),
+            (5.0_f32, 5.0_f32)
+        );
+    }
+
+    #[test]
+    fn default_fade_matches_squared_smoothstep() {
+        let fade = GridFade::default();
+        let radius = 10.0_f32;
+        for distance in [0.0_f32, 7.0_f32, 8.0_f32, 9.0_f32, 9.9_f32] {
+            // Tracked grids originally faded with `smoothstep(0.6 * r², r², d²)`
+            let t = ((distance * distance - 0.6_f32 * radius * radius)
+                / (0.4_f32 * radius * radius))
+                .clamp(0.0_f32, 1.0_f32);
+            let expected = 1.0_f32 - t * t * (3.0_f32 - 2.0_f32 * t);
+            assert!((fade.alpha(distance, radius) - expected).abs() < 1e-4_f32);
+        }
+        assert_eq!(fade.alpha(10.1_f32, radius), 0.0_f32);
+    }
+
+    #[test]
+    fn fade_alpha_follows_curve() {
+        let fade = GridFade {
+            curve: FadeCurve::Linear,
+            ..GridFade::new(2.0_f32, 6.0_f32)
+        };
+        assert_eq!(fade.alpha(1.0_f32, 0.0_f32), 1.0_f32);
+        assert_eq!(fade.alpha(4.0_f32, 0.0_f32), 0.5_f32);
+        assert_eq!(fade.alpha(6.0_f32, 0.0_f32), 0.0_f32);
+        assert_eq!(fade.alpha(7.0_f32, 0.0_f32), 0.0_f32);
+    }
+}
@@ -0,0 +1,341 @@
+#![allow(clippy::type_complexity)]
+
+use bevy::color::palettes::tailwind;
+#[cfg(feature = "picking")]
+use bevy::picking::events::{Click, Pointer};
+use bevy::prelude::*;
+use bevy::transform::helper::TransformHelper;
+use bevy::ui::UiTargetCamera;
+
+use crate::*;
+
+/// Snapping of the points of a `GridMeasurement`
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MeasurementSnapping {
+    /// Points are measured as is
+    None,
+    /// Points are snapped to the grid's line intersections
+    Grid,
+    /// Points are snapped to the line intersections of the grid's `SubGrid`, or of the grid without one
+    #[default]
+    SubGrid,
+}
+
+/// Measures the distance between two points on the plane of a grid.
+/// Spawn it on its own entity, the measured grid being referenced by `grid`.
+///
+/// A dimension line is drawn between both points with the `DebugGridGizmos`, along with the path following the grid's axis.
+/// A label shows the distance, the offset in cells along both axis of the grid's plane, and the Manhattan distance in cells,
+/// as seen by the tracked camera, or the `tracking_override` of the grid's `TrackedGrid`.
+///
+/// With the `picking` feature enabled, an `interactive` measurement sets its points by clicking on the grid.
+#[derive(Component, Clone, Debug)]
+pub struct GridMeasurement {
+    /// The measured grid entity
+    pub grid: Entity,
+    /// World position of the first point
+    pub start: Option<Vec3>,
+    /// World position of the second point
+    pub end: Option<Vec3>,
+    /// Snapping of both points
+    pub snapping: MeasurementSnapping,
+    /// Whether clicks on the grid set the points, alternating between the first and the second one
+    pub interactive: bool,
+    /// Color of the dimension line and of the label
+    pub color: Color,
+    /// Font size of the label
+    pub font_size: f32,
+}
+
+impl GridMeasurement {
+    pub const DEFAULT_SRGBA: Srgba = tailwind::YELLOW_300;
+
+    /// Creates a measurement between two world positions on a grid
+    pub fn new(grid: Entity, start: Vec3, end: Vec3) -> Self {
+        Self {
+            start: Some(start),
+            end: Some(end),
+            ..Self::interactive(grid)
+        }
+    }
+
+    /// Creates a measurement without points, set by clicking on the grid
+    pub fn interactive(grid: Entity) -> Self {
+        Self {
+            grid,
+            start: None,
+            end: None,
+            snapping: MeasurementSnapping::default(),
+            interactive: true,
+            color: Color::Srgba(Self::DEFAULT_SRGBA),
+            font_size: 14.0_f32,
+        }
+    }
+
+    /// Returns a point of the measurement, snapped to the grid
+    fn snap(&self, grids: &GridQuery, point: Vec3) -> Option<Vec3> {
+        match self.snapping {
+            MeasurementSnapping::None => Some(point),
+            MeasurementSnapping::Grid => grids.snap(self.grid, point),
+            MeasurementSnapping::SubGrid => grids.snap_to_sub_grid(self.grid, point),
+        }
+    }
+
+    /// Measures the distance between both points, once snapped.
+    /// Returns `None` while a point is missing, or when the grid does not exist.
+    pub fn measure(&self, grids: &GridQuery) -> Option<GridDistance> {
+        let start = self.snap(grids, self.start?)?;
+        let end = self.snap(grids, self.end?)?;
+        let cells = grids.cell_delta(self.grid, start, end)?;
+        Some(GridDistance {
+            start,
+            end,
+            distance: start.distance(end),
+            cells,
+            manhattan: cells.abs().element_sum(),
+        })
+    }
+}
+
+/// The distance between two points on the plane of a grid, measured by a `GridMeasurement`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GridDistance {
+    /// Snapped world position of the first point
+    pub start: Vec3,
+    /// Snapped world position of the second point
+    pub end: Vec3,
+    /// Distance between both points, in world units
+    pub distance: f32,
+    /// Offset from the first point to the second, in cells along both axis of the grid's plane
+    pub cells: Vec2,
+    /// Manhattan distance between both points, in cells
+    pub manhattan: f32,
+}
+
+/// The label spawned by a `GridMeasurement`.
+/// Labels are UI nodes, and are thus not children of their measurement.
+#[derive(Component, Clone, Debug)]
+pub struct GridMeasurementLabel {
+    /// The measurement entity this label belongs to
+    pub measurement: Entity,
+}
+
+/// Returns the resolution of a measurement's snapping, in cells
+fn snapping_resolution(snapping: MeasurementSnapping, sub_grid: Option<&SubGrid>) -> f32 {
+    match (snapping, sub_grid) {
+        (MeasurementSnapping::SubGrid, Some(sub_grid)) => 1.0_f32 / (sub_grid.count + 1) as f32,
+        _ => 1.0_f32,
+    }
+}
+
+/// System spawning the label of every added `GridMeasurement`
+pub fn grid_measurement_label_spawner(
+    mut commands: Commands,
+    query: Query<(Entity, &GridMeasurement), Added<GridMeasurement>>,
+) {
+    for (entity, measurement) in query.iter() {
+        commands.spawn((
+            GridMeasurementLabel {
+                measurement: entity,
+            },
+            Text::default(),
+            TextFont::from_font_size(measurement.font_size),
+            TextColor(measurement.color),
+            Node {
+                position_type: PositionType::Absolute,
+                padding: UiRect::all(Val::Px(4.0_f32)),
+                ..default()
+            },
+            BackgroundColor(Color::Srgba(tailwind::GRAY_900.with_alpha(0.8_f32))),
+            Visibility::Hidden,
+        ));
+    }
+}
+
+/// System drawing the dimension line of every `GridMeasurement` with both points set,
+/// or a marker on the first point while the second one is missing
+pub fn draw_grid_measurements(
+    mut gizmos: Gizmos<DebugGridGizmos>,
+    query: Query<&GridMeasurement>,
+    query_grid: Query<(&Grid, &GlobalTransform)>,
+    grids: GridQuery,
+) {
+    for measurement in query.iter() {
+        let Ok((grid, transform)) = query_grid.get(measurement.grid) else {
+            continue;
+        };
+        let Some(alignment) = grids.alignment(measurement.grid) else {
+            continue;
+        };
+        let normal = transform
+            .affine()
+            .transform_vector3(alignment.to_axis_vec3())
+            .normalize_or_zero();
+        let tick = grid.spacing * 0.25_f32;
+
+        let Some(distance) = measurement.measure(&grids) else {
+            if let Some(start) = measurement
+                .start
+                .and_then(|start| measurement.snap(&grids, start))
+            {
+                let rotation = Quat::from_rotation_arc(Vec3::Z, normal);
+                gizmos.circle(Isometry3d::new(start, rotation), tick, measurement.color);
+            }
+            continue;
+        };
+
+        // Dimension line, with ticks perpendicular to it within the grid's plane
+        let (start, end) = (distance.start, distance.end);
+        gizmos.line(start, end, measurement.color);
+        let across = normal.cross(end - start).normalize_or_zero() * tick;
+        for point in [start, end] {
+            gizmos.line(point - across, point + across, measurement.color);
+        }
+
+        // Path along the grid's axis, going along the first axis of the plane, then the second
+        let [first, _] = alignment.plane_axis();
+        let along_first = transform
+            .affine()
+            .transform_vector3(first.to_axis_vec3() * distance.cells.x * grid.spacing);
+        let faded = measurement
+            .color
+            .with_alpha(measurement.color.alpha() * 0.4_f32);
+        gizmos.line(start, start + along_first, faded);
+        gizmos.line(start + along_first, end, faded);
+    }
+}
+
+/// System placing the label of every `GridMeasurement` over the middle of its dimension line,
+/// as seen by the camera tracked by the component `T`, or by the `tracking_override` of the grid's `TrackedGrid`.
+/// Labels of measurements that no longer exist are despawned.
+#[allow(clippy::too_many_arguments)]
+pub fn grid_measurement_label_updater<T: Component>(
+    mut commands: Commands,
+    mut query_labels: Query<(
+        Entity,
+        &GridMeasurementLabel,
+        &mut Node,
+        &mut Text,
+        &mut TextFont,
+        &mut TextColor,
+        &mut Visibility,
+        &ComputedNode,
+        Option<&UiTargetCamera>,
+    )>,
+    query_measurement: Query<&GridMeasurement>,
+    query_grid: Query<(Option<&TrackedGrid>, Option<&SubGrid>)>,
+    query_camera: Query<&Camera>,
+    query_tracked: Query<Entity, (With<T>, With<Camera>, TrackedEntityFilter)>,
+    grids: GridQuery,
+    transform_helper: TransformHelper,
+) {
+    for (
+        entity,
+        label,
+        mut node,
+        mut text,
+        mut text_font,
+        mut text_color,
+        mut visibility,
+        computed_node,
+        target_camera,
+    ) in query_labels.iter_mut()
+    {
+        let Ok(measurement) = query_measurement.get(label.measurement) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        let (Some(distance), Ok((tracked, sub_grid))) = (
+            measurement.measure(&grids),
+            query_grid.get(measurement.grid),
+        ) else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        let Some(camera_entity) = tracked
+            .and_then(|tracked| tracked.tracking_override)
+            .filter(|entity| query_camera.contains(*entity))
+            .or_else(|| query_tracked.single().ok())
+        else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        let (Ok(camera), Ok(camera_transform)) = (
+            query_camera.get(camera_entity),
+            transform_helper.compute_global_transform(camera_entity),
+        ) else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        let middle = distance.start.midpoint(distance.end);
+        let Ok(viewport_position) = camera.world_to_viewport(&camera_transform, middle) else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        if target_camera.is_none_or(|target_camera| target_camera.0 != camera_entity) {
+            commands
+                .entity(entity)
+                .insert(UiTargetCamera(camera_entity));
+        }
+        visibility.set_if_neq(Visibility::Inherited);
+        text_color.set_if_neq(TextColor(measurement.color));
+        if text_font.font_size != measurement.font_size {
+            text_font.font_size = measurement.font_size;
+        }
+
+        let Some(alignment) = grids.alignment(measurement.grid) else {
+            continue;
+        };
+        let [first, second] = alignment.plane_axis();
+        let cell_precision =
+            coordinate_precision(snapping_resolution(measurement.snapping, sub_grid));
+        let content = format!(
+            "{:.*}\n{:?}: {:.*}  {:?}: {:.*}\nManhattan: {:.*}",
+            cell_precision.max(2),
+            distance.distance,
+            first,
+            cell_precision,
+            distance.cells.x,
+            second,
+            cell_precision,
+            distance.cells.y,
+            cell_precision,
+            distance.manhattan,
+        );
+        if text.0 != content {
+            text.0 = content;
+        }
+
+        // Center the label on the dimension line
+        let size = computed_node.size() * computed_node.inverse_scale_factor();
+        let top_left = viewport_position - size * 0.5_f32;
+        let (left, top) = (Val::Px(top_left.x), Val::Px(top_left.y));
+        if node.left != left || node.top != top {
+            node.left = left;
+            node.top = top;
+        }
+    }
+}
+
+/// Observer setting the points of interactive measurements when their grid is clicked.
+/// The first click sets the first point, the second one the second point, and the next one starts a new measurement.
+#[cfg(feature = "picking")]
+pub fn grid_measurement_on_click(
+    click: On<Pointer<Click>>,
+    mut query: Query<&mut GridMeasurement>,
+) {
+    let Some(position) = click.hit.position else {
+        return;
+    };
+    for mut measurement in query.iter_mut() {
+        if !measurement.interactive || measurement.grid != click.entity {
+            continue;
+        }
+        if measurement.start.is_none() || measurement.end.is_some() {
+            measurement.start = Some(position);
+            measurement.end = None;
+        } else {
+            measurement.end = Some(position);
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+use crate::*;
+
+/// Tracks the cell of a grid occupied by an entity, using the entity's `GlobalTransform`.
+/// Moving between cells sends `CellExited` and `CellEntered` messages, and updates the `GridOccupancy` resource.
+///
+/// Cells are indexed along the two axis of the grid's plane (see `GridAlignment::plane_axis`),
+/// in world space for tracked grids, and in the grid's local space otherwise.
+/// Cells beyond the extent of the grid are occupied as well.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct GridOccupant {
+    /// The grid entity whose cells are occupied
+    pub grid: Entity,
+}
+
+impl GridOccupant {
+    /// Tracks the cells of a grid occupied by the entity
+    pub const fn new(grid: Entity) -> Self {
+        Self { grid }
+    }
+}
+
+/// Message sent when a `GridOccupant` enters a cell, including when it first appears on its grid
+#[derive(Message, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CellEntered {
+    /// The entity with the `GridOccupant`
+    pub occupant: Entity,
+    /// The occupied grid entity
+    pub grid: Entity,
+    /// Index of the entered cell
+    pub cell: IVec2,
+}
+
+/// Message sent when a `GridOccupant` exits a cell, including when it is removed or despawned
+#[derive(Message, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CellExited {
+    /// The entity with the `GridOccupant`
+    pub occupant: Entity,
+    /// The previously occupied grid entity
+    pub grid: Entity,
+    /// Index of the exited cell
+    pub cell: IVec2,
+}
+
+/// Index of the entities occupying each cell of grids, maintained for entities with a `GridOccupant`
+#[derive(Resource, Default, Debug)]
+pub struct GridOccupancy {
+    /// Occupants of each cell, per grid
+    grids: HashMap<Entity, HashMap<IVec2, Vec<Entity>>>,
+    /// Grid and cell of each occupant
+    occupants: HashMap<Entity, (Entity, IVec2)>,
+}
+
+impl GridOccupancy {
+    /// Returns the entities occupying a cell of a grid
+    pub fn occupants(&self, grid: Entity, cell: IVec2) -> &[Entity] {
+        self.grids
+            .get(&grid)
+            .and_then(|cells| cells.get(&cell))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns every occupied cell of a grid, along with its occupants
+    pub fn cells(&self, grid: Entity) -> impl Iterator<Item = (IVec2, &[Entity])> {
+        self.grids
+            .get(&grid)
+            .into_iter()
+            .flatten()
+            .map(|(cell, occupants)| (*cell, occupants.as_slice()))
+    }
+
+    /// Returns the grid and the cell occupied by an entity
+    pub fn cell_of(&self, occupant: Entity) -> Option<(Entity, IVec2)> {
+        self.occupants.get(&occupant).copied()
+    }
+
+    /// Returns whether a cell of a grid is occupied by any entity
+    pub fn is_occupied(&self, grid: Entity, cell: IVec2) -> bool {
+        !self.occupants(grid, cell).is_empty()
+    }
+
+    /// Records an occupant in a cell
+    fn enter(&mut self, occupant: Entity, grid: Entity, cell: IVec2) {
+        self.occupants.insert(occupant, (grid, cell));
+        self.grids
+            .entry(grid)
+            .or_default()
+            .entry(cell)
+            .or_default()
+            .push(occupant);
+    }
+
+    /// Removes an occupant from its cell, returning the grid and the cell it occupied
+    fn exit(&mut self, occupant: Entity) -> Option<(Entity, IVec2)> {
+        let (grid, cell) = self.occupants.remove(&occupant)?;
+        if let Some(cells) = self.grids.get_mut(&grid) {
+            if let Some(occupants) = cells.get_mut(&cell) {
+                occupants.retain(|entity| *entity != occupant);
+                if occupants.is_empty() {
+                    cells.remove(&cell);
+                }
+            }
+            if cells.is_empty() {
+                self.grids.remove(&grid);
+            }
+        }
+        Some((grid, cell))
+    }
+}
+
+/// System updating the cell of every `GridOccupant` within the `GridOccupancy`,
+/// sending `CellExited` and `CellEntered` messages when an occupant changes cells
+pub fn grid_occupancy_updater(
+    mut occupancy: ResMut<GridOccupancy>,
+    mut removed: RemovedComponents<GridOccupant>,
+    mut exited_writer: MessageWriter<CellExited>,
+    mut entered_writer: MessageWriter<CellEntered>,
+    query_occupant: Query<(Entity, &GridOccupant, &GlobalTransform)>,
+    grids: GridQuery,
+) {
+    for occupant in removed.read() {
+        if query_occupant.contains(occupant) {
+            continue;
+        }
+        if let Some((grid, cell)) = occupancy.exit(occupant) {
+            exited_writer.write(CellExited {
+                occupant,
+                grid,
+                cell,
+            });
+        }
+    }
+
+    for (occupant, grid_occupant, transform) in query_occupant.iter() {
+        let grid = grid_occupant.grid;
+        let cell = grids.cell(grid, transform.translation());
+        let previous = occupancy.cell_of(occupant);
+        if previous == cell.map(|cell| (grid, cell)) {
+            continue;
+        }
+        if let Some((grid, cell)) = occupancy.exit(occupant) {
+            exited_writer.write(CellExited {
+                occupant,
+                grid,
+                cell,
+            });
+        }
+        // The occupant has no cell while its grid does not exist
+        if let Some(cell) = cell {
+            occupancy.enter(occupant, grid, cell);
+            entered_writer.write(CellEntered {
+                occupant,
+                grid,
+                cell,
+            });
+        }
+    }
+}
@@ -0,0 +1,327 @@
+#![allow(clippy::type_complexity)]
+
+use bevy::asset::RenderAssetUsages;
+use bevy::camera::visibility::RenderLayers;
+use bevy::camera::{ClearColorConfig, RenderTarget, ScalingMode, Viewport};
+use bevy::light::NotShadowCaster;
+use bevy::prelude::*;
+use bevy::render::render_resource::PrimitiveTopology;
+use bevy::window::PrimaryWindow;
+
+use crate::*;
+
+/// Distance of the overlay camera to the triad's origin
+const OVERLAY_CAMERA_DISTANCE: f32 = 3.0_f32;
+
+/// Directions the camera can be snapped to, looking back at origin from each of them
+const SNAP_DIRECTIONS: [Dir3; 6] = [
+    Dir3::X,
+    Dir3::NEG_X,
+    Dir3::Y,
+    Dir3::NEG_Y,
+    Dir3::Z,
+    Dir3::NEG_Z,
+];
+
+/// Corner of a camera's viewport an `OrientationGizmo` is rendered in
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ViewportCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight,
+}
+
+/// Renders an axis triad in a corner of a camera's viewport, showing the camera's orientation.
+/// Spawn it on a camera for it to have effect.
+///
+/// The triad is rendered by an overlay camera on its own render layer,
+/// which grids and tools are never tracked to, even with the `DebugGridPlugin` tracking every `Camera`.
+///
+/// A `clickable` gizmo snaps the camera onto an axis when the axis' end is clicked, looking back at origin
+/// from the camera's distance to it, and sends an `OrientationSnapped` message.
+#[derive(Component, Clone, Debug)]
+pub struct OrientationGizmo {
+    /// Colors and markers of the triad's axis, axis without a color are not rendered
+    pub axis: GridAxis,
+    /// Corner of the viewport the triad is rendered in
+    pub corner: ViewportCorner,
+    /// Size of the triad's square viewport, in logical pixels
+    pub size: f32,
+    /// Distance between the triad's viewport and the edges of the camera's viewport, in logical pixels
+    pub margin: f32,
+    /// Render layer of the triad and its overlay camera, which should not be used by anything else
+    pub render_layer: usize,
+    /// Order of the overlay camera, which should render after the camera
+    pub order: isize,
+    /// Whether clicking the end of an axis snaps the camera onto it, looking back at origin
+    pub clickable: bool,
+}
+
+impl OrientationGizmo {
+    pub const DEFAULT_RENDER_LAYER: usize = 31;
+}
+
+impl Default for OrientationGizmo {
+    fn default() -> Self {
+        Self {
+            axis: GridAxis::new_rgb().with_markers(),
+            corner: ViewportCorner::default(),
+            size: 96.0_f32,
+            margin: 8.0_f32,
+            render_layer: Self::DEFAULT_RENDER_LAYER,
+            order: 100,
+            clickable: true,
+        }
+    }
+}
+
+/// Marks the overlay camera and the triad spawned by an `OrientationGizmo`.
+/// They are not children of the camera, so that the camera's transform does not affect them.
+#[derive(Component, Clone, Debug)]
+pub struct OrientationGizmoOverlay {
+    /// The camera entity with the `OrientationGizmo`
+    pub camera: Entity,
+}
+
+/// Message sent when a clickable `OrientationGizmo` snaps its camera onto an axis
+#[derive(Message, Clone, Copy, Debug, PartialEq)]
+pub struct OrientationSnapped {
+    /// The camera entity with the `OrientationGizmo`
+    pub camera: Entity,
+    /// Direction the camera is looking from, towards origin
+    pub direction: Dir3,
+}
+
+/// Returns the rotation of a camera looking back at origin from a direction.
+/// Top and bottom views keep `-Z` and `Z` upwards, respectively.
+fn snapped_rotation(direction: Dir3) -> Quat {
+    let up = if direction.y.abs() > 0.5_f32 {
+        Vec3::NEG_Z * direction.y.signum()
+    } else {
+        Vec3::Y
+    };
+    Transform::default()
+        .looking_to(-direction.as_vec3(), up)
+        .rotation
+}
+
+/// System spawning the overlay camera and the triad of every added or changed `OrientationGizmo`,
+/// replacing previous ones
+pub fn orientation_gizmo_spawner(
+    mut commands: Commands,
+    query_gizmo: Query<(Entity, &OrientationGizmo, &RenderTarget), Changed<OrientationGizmo>>,
+    query_overlay: Query<(Entity, &OrientationGizmoOverlay)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut simple_materials: ResMut<Assets<SimpleLineMaterial>>,
+) {
+    for (camera, gizmo, target) in query_gizmo.iter() {
+        for (entity, overlay) in query_overlay.iter() {
+            if overlay.camera == camera {
+                commands.entity(entity).despawn();
+            }
+        }
+        let render_layers = RenderLayers::layer(gizmo.render_layer);
+
+        commands.spawn((
+            OrientationGizmoOverlay { camera },
+            Camera3d::default(),
+            Camera {
+                order: gizmo.order,
+                clear_color: ClearColorConfig::None,
+                // The viewport is set once the camera's viewport is known
+                is_active: false,
+                ..default()
+            },
+            target.clone(),
+            Projection::from(OrthographicProjection {
+                scaling_mode: ScalingMode::Fixed {
+                    width: 2.6_f32,
+                    height: 2.6_f32,
+                },
+                ..OrthographicProjection::default_3d()
+            }),
+            Transform::from_xyz(0.0_f32, 0.0_f32, OVERLAY_CAMERA_DISTANCE),
+            render_layers.clone(),
+        ));
+
+        let mut lines = [GridAlignment::X, GridAlignment::Y, GridAlignment::Z]
+            .into_iter()
+            .flat_map(|alignment| gizmo.axis.create_axis_lines(1.0_f32, 0.5_f32, alignment))
+            .collect::<Vec<_>>();
+        if let Some(color) = gizmo.axis.origin {
+            lines.push((GridAxis::create_origin_marker(0.5_f32), color));
+        }
+        commands
+            .spawn((
+                OrientationGizmoOverlay { camera },
+                Transform::default(),
+                Visibility::default(),
+                render_layers.clone(),
+            ))
+            .with_children(|children| {
+                for (vertices, color) in lines {
+                    let mut mesh = Mesh::new(PrimitiveTopology::LineList, RenderAssetUsages::all());
+                    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertices);
+                    children.spawn((
+                        Mesh3d(meshes.add(mesh)),
+                        NotShadowCaster,
+                        Transform::default(),
+                        Visibility::default(),
+                        MeshMaterial3d(
+                            simple_materials
+                                .add(SimpleLineMaterial::from_color(color, AlphaMode::Blend)),
+                        ),
+                        render_layers.clone(),
+                    ));
+                }
+            });
+    }
+}
+
+/// System orbiting every overlay camera around its triad, following the rotation of the camera with the `OrientationGizmo`,
+/// and placing its viewport in the gizmo's corner.
+/// Overlays of cameras without an `OrientationGizmo` are despawned.
+pub fn orientation_gizmo_updater(
+    mut commands: Commands,
+    mut query_camera: Query<
+        (
+            Entity,
+            &OrientationGizmoOverlay,
+            &mut Camera,
+            &mut Transform,
+            &mut GlobalTransform,
+        ),
+        Without<OrientationGizmo>,
+    >,
+    query_triad: Query<(Entity, &OrientationGizmoOverlay), Without<Camera>>,
+    query_gizmo: Query<(
+        &OrientationGizmo,
+        &Camera,
+        Ref<RenderTarget>,
+        &GlobalTransform,
+    )>,
+) {
+    for (entity, overlay) in query_triad.iter() {
+        if !query_gizmo.contains(overlay.camera) {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    for (entity, overlay, mut overlay_camera, mut transform, mut global_transform) in
+        query_camera.iter_mut()
+    {
+        let Ok((gizmo, camera, target, camera_transform)) = query_gizmo.get(overlay.camera) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        if target.is_changed() {
+            commands.entity(entity).insert(target.clone());
+        }
+
+        // Transforms are already propagated, the global transform is set as well
+        let rotation = camera_transform.rotation();
+        let translation = rotation * Vec3::Z * OVERLAY_CAMERA_DISTANCE;
+        if transform.rotation != rotation || transform.translation != translation {
+            transform.rotation = rotation;
+            transform.translation = translation;
+            *global_transform = GlobalTransform::from(*transform);
+        }
+
+        let (Some(rect), Some(scale)) = (
+            camera.physical_viewport_rect(),
+            camera.target_scaling_factor(),
+        ) else {
+            overlay_camera.is_active = false;
+            continue;
+        };
+        let size = ((gizmo.size * scale) as u32)
+            .min(rect.width())
+            .min(rect.height());
+        let margin = (gizmo.margin * scale) as u32;
+        let (min, max) = (
+            rect.min + margin,
+            rect.max.saturating_sub(UVec2::splat(margin + size)),
+        );
+        let physical_position = match gizmo.corner {
+            ViewportCorner::TopLeft => min,
+            ViewportCorner::TopRight => UVec2::new(max.x, min.y),
+            ViewportCorner::BottomLeft => UVec2::new(min.x, max.y),
+            ViewportCorner::BottomRight => max,
+        }
+        .min(rect.max.saturating_sub(UVec2::splat(size)))
+        .max(rect.min);
+        let physical_size = UVec2::splat(size.max(1));
+        let unchanged = overlay_camera.viewport.as_ref().is_some_and(|viewport| {
+            viewport.physical_position == physical_position
+                && viewport.physical_size == physical_size
+        });
+        if !unchanged {
+            overlay_camera.viewport = Some(Viewport {
+                physical_position,
+                physical_size,
+                ..default()
+            });
+        }
+        if !overlay_camera.is_active {
+            overlay_camera.is_active = true;
+        }
+    }
+}
+
+/// System snapping cameras onto an axis, looking back at origin, when the end of the axis is clicked on their `OrientationGizmo`.
+/// Cameras keep their distance from origin.
+pub fn orientation_gizmo_click(
+    mouse: Res<ButtonInput<MouseButton>>,
+    query_window: Query<&Window, With<PrimaryWindow>>,
+    query_overlay: Query<(&OrientationGizmoOverlay, &Camera, &GlobalTransform)>,
+    mut query_gizmo: Query<(&OrientationGizmo, &mut Transform)>,
+    mut snapped_writer: MessageWriter<OrientationSnapped>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) {
+        return;
+    }
+    let Some(cursor) = query_window
+        .single()
+        .ok()
+        .and_then(|window| window.cursor_position())
+    else {
+        return;
+    };
+    for (overlay, overlay_camera, overlay_transform) in query_overlay.iter() {
+        let Ok((gizmo, mut transform)) = query_gizmo.get_mut(overlay.camera) else {
+            continue;
+        };
+        if !gizmo.clickable
+            || !overlay_camera.is_active
+            || !overlay_camera
+                .logical_viewport_rect()
+                .is_some_and(|rect| rect.contains(cursor))
+        {
+            continue;
+        }
+        // The axis end nearest to the cursor, within a fraction of the triad's size
+        let Some(direction) = SNAP_DIRECTIONS
+            .into_iter()
+            .filter_map(|direction| {
+                let end = overlay_camera
+                    .world_to_viewport(overlay_transform, direction.as_vec3())
+                    .ok()?;
+                Some((direction, end.distance_squared(cursor)))
+            })
+            .filter(|(_, distance_squared)| *distance_squared < (gizmo.size * 0.15_f32).powi(2))
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(direction, _)| direction)
+        else {
+            continue;
+        };
+        // The camera keeps its distance from origin
+        transform.translation = direction.as_vec3() * transform.translation.length();
+        transform.rotation = snapped_rotation(direction);
+        snapped_writer.write(OrientationSnapped {
+            camera: overlay.camera,
+            direction,
+        });
+    }
+}
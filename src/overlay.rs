@@ -0,0 +1,339 @@
+#![allow(clippy::type_complexity)]
+
+use bevy::asset::RenderAssetUsages;
+use bevy::camera::visibility::RenderLayers;
+#[cfg(feature = "3d")]
+use bevy::light::NotShadowCaster;
+use bevy::platform::collections::{HashMap, HashSet};
+use bevy::prelude::*;
+use bevy::render::render_resource::PrimitiveTopology;
+#[cfg(feature = "2d")]
+use bevy::sprite_render::AlphaMode2d;
+
+use crate::*;
+
+/// Vertex count of a single filled cell, made of two triangles
+const CELL_VERTEX_COUNT: usize = 6;
+
+/// Depth of cell overlays below the grid's lines, in `SUB_GRID_VERTICAL_OFFSET`.
+/// Below the sub-grid, which is itself below the grid's lines.
+const OVERLAY_DEPTH: f32 = 2.0_f32;
+
+/// Fills individual cells of a grid with a color, as translucent quads below the grid's lines.
+/// Spawn it next to a grid for it to have effect.
+///
+/// Cells are indexed along the two axis of the grid's plane (see `GridAlignment::plane_axis`),
+/// in world space for tracked grids, and in the grid's local space otherwise.
+/// Cells beyond the extent of the grid are filled as well.
+///
+/// Changes made through `insert`, `remove` and `clear` only update the affected cells of the overlay's mesh.
+#[derive(Component, Clone, Debug, Default)]
+pub struct GridCellOverlay {
+    /// Color of every filled cell
+    cells: HashMap<IVec2, Color>,
+    /// Cells changed since the overlay's mesh was last updated
+    changed: HashSet<IVec2>,
+}
+
+impl GridCellOverlay {
+    /// Creates an empty overlay
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the color of a cell, if it is filled
+    pub fn get(&self, cell: IVec2) -> Option<Color> {
+        self.cells.get(&cell).copied()
+    }
+
+    /// Fills a cell with a color, returning its previous color
+    pub fn insert(&mut self, cell: IVec2, color: Color) -> Option<Color> {
+        self.changed.insert(cell);
+        self.cells.insert(cell, color)
+    }
+
+    /// Clears the color of a cell, returning it
+    pub fn remove(&mut self, cell: IVec2) -> Option<Color> {
+        let color = self.cells.remove(&cell);
+        if color.is_some() {
+            self.changed.insert(cell);
+        }
+        color
+    }
+
+    /// Clears the color of every cell
+    pub fn clear(&mut self) {
+        self.changed
+            .extend(self.cells.drain().map(|(cell, _)| cell));
+    }
+
+    /// Returns every filled cell along with its color
+    pub fn cells(&self) -> &HashMap<IVec2, Color> {
+        &self.cells
+    }
+
+    /// Returns the number of filled cells
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns whether no cell is filled
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+}
+
+impl FromIterator<(IVec2, Color)> for GridCellOverlay {
+    fn from_iter<I: IntoIterator<Item = (IVec2, Color)>>(iter: I) -> Self {
+        let cells = iter.into_iter().collect::<HashMap<_, _>>();
+        Self {
+            changed: cells.keys().copied().collect(),
+            cells,
+        }
+    }
+}
+
+/// Component to determine children spawned by a `GridCellOverlay`.
+/// Keeps track of the cell drawn by each quad of the child's mesh.
+#[derive(Component)]
+pub struct GridCellOverlayChild {
+    /// The child's mesh, updated in place when cells change
+    mesh: Handle<Mesh>,
+    /// Cell of each quad, in the order of the mesh's vertices
+    quads: Vec<IVec2>,
+    /// Index of the quad of each cell
+    indices: HashMap<IVec2, usize>,
+}
+
+/// Returns the vertices of the two triangles filling a cell, in the grid's local space
+fn cell_quad(grid: &Grid, alignment: &GridAlignment, cell: IVec2) -> [[f32; 3]; CELL_VERTEX_COUNT] {
+    let [first, second] = alignment.plane_axis();
+    let corner = |offset: IVec2| {
+        let position = (cell + offset).as_vec2() * grid.spacing;
+        (first.to_axis_vec3() * position.x + second.to_axis_vec3() * position.y).to_array()
+    };
+    let [a, b, c, d] = [IVec2::ZERO, IVec2::X, IVec2::ONE, IVec2::Y].map(corner);
+    [a, b, c, a, c, d]
+}
+
+impl GridCellOverlayChild {
+    /// Applies changed cells of an overlay to the child's mesh, appending, recoloring and removing quads
+    fn apply(
+        &mut self,
+        mesh: &mut Mesh,
+        grid: &Grid,
+        alignment: &GridAlignment,
+        overlay: &GridCellOverlay,
+        cells: impl IntoIterator<Item = IVec2>,
+    ) {
+        let mut positions = match mesh.remove_attribute(Mesh::ATTRIBUTE_POSITION) {
+            Some(bevy::mesh::VertexAttributeValues::Float32x3(positions)) => positions,
+            _ => Vec::new(),
+        };
+        let mut colors = match mesh.remove_attribute(Mesh::ATTRIBUTE_COLOR) {
+            Some(bevy::mesh::VertexAttributeValues::Float32x4(colors)) => colors,
+            _ => Vec::new(),
+        };
+
+        for cell in cells {
+            match (overlay.get(cell), self.indices.get(&cell).copied()) {
+                (Some(color), Some(index)) => {
+                    let color = LinearRgba::from(color).to_f32_array();
+                    colors[index * CELL_VERTEX_COUNT..(index + 1) * CELL_VERTEX_COUNT].fill(color);
+                }
+                (Some(color), None) => {
+                    self.indices.insert(cell, self.quads.len());
+                    self.quads.push(cell);
+                    positions.extend(cell_quad(grid, alignment, cell));
+                    colors.extend([LinearRgba::from(color).to_f32_array(); CELL_VERTEX_COUNT]);
+                }
+                (None, Some(index)) => {
+                    // Moves the last quad in place of the removed one
+                    let start = index * CELL_VERTEX_COUNT;
+                    let last = positions.len() - CELL_VERTEX_COUNT;
+                    positions.copy_within(last.., start);
+                    colors.copy_within(last.., start);
+                    positions.truncate(last);
+                    colors.truncate(last);
+                    self.indices.remove(&cell);
+                    self.quads.swap_remove(index);
+                    if let Some(moved) = self.quads.get(index) {
+                        self.indices.insert(*moved, index);
+                    }
+                }
+                (None, None) => {}
+            }
+        }
+
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+    }
+}
+
+/// Returns the translation of an overlay's mesh, `depth` times `SUB_GRID_VERTICAL_OFFSET` below the grid's lines,
+/// moving it back to world space cells for tracked grids
+pub(crate) fn overlay_translation(
+    grid: &Grid,
+    tracked: Option<&TrackedGrid>,
+    alignment: &GridAlignment,
+    translation: Vec3,
+    depth: f32,
+) -> Vec3 {
+    let [first, second] = alignment.plane_axis();
+    let cell_offset =
+        tracked_cell_offset(grid, tracked, alignment, translation).as_vec2() * grid.spacing;
+    alignment.to_axis_vec3() * SUB_GRID_VERTICAL_OFFSET * depth
+        - first.to_axis_vec3() * cell_offset.x
+        - second.to_axis_vec3() * cell_offset.y
+}
+
+/// System for meshing the overlay of grids with a `GridCellOverlay`, remeshing every cell when the grid changes
+pub fn grid_cell_overlay_mesher(
+    mut commands: Commands,
+    mut query_parent: Query<
+        (
+            Entity,
+            &Grid,
+            &mut GridCellOverlay,
+            Option<&TrackedGrid>,
+            &Transform,
+            Option<&RenderLayers>,
+            Option<&Children>,
+        ),
+        Or<(
+            Changed<Grid>,
+            Added<GridCellOverlay>,
+            Changed<TrackedGrid>,
+            Changed<RenderLayers>,
+        )>,
+    >,
+    query_children: Query<Entity, With<GridCellOverlayChild>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    #[cfg(feature = "3d")] mut standard_materials: ResMut<Assets<StandardMaterial>>,
+    #[cfg(feature = "2d")] mut color_materials: ResMut<Assets<ColorMaterial>>,
+    #[cfg(feature = "2d")] query_2d: Query<(), With<Grid2d>>,
+) {
+    for (entity, grid, mut overlay, tracked, transform, render_layers, children) in
+        query_parent.iter_mut()
+    {
+        if let Some(children) = children {
+            despawn_children_of_type(&mut commands, entity, children, &query_children);
+        }
+
+        #[cfg(feature = "2d")]
+        let is_2d = query_2d.contains(entity);
+        #[cfg(not(feature = "2d"))]
+        let is_2d = false;
+        let alignment = grid_plane_alignment(tracked, is_2d);
+
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::all());
+        let mut child = GridCellOverlayChild {
+            mesh: Handle::default(),
+            quads: Vec::new(),
+            indices: HashMap::new(),
+        };
+        let cells = overlay.cells.keys().copied().collect::<Vec<_>>();
+        child.apply(&mut mesh, grid, &alignment, &overlay, cells);
+        // Every cell is now meshed, without flagging the overlay as changed again
+        overlay.bypass_change_detection().changed.clear();
+        let mesh = meshes.add(mesh);
+        child.mesh = mesh.clone();
+
+        commands.entity(entity).with_children(|children| {
+            let mut child_commands = children.spawn((
+                child,
+                Transform::from_translation(overlay_translation(
+                    grid,
+                    tracked,
+                    &alignment,
+                    transform.translation,
+                    OVERLAY_DEPTH,
+                )),
+                Visibility::default(),
+            ));
+            #[cfg(feature = "2d")]
+            if is_2d {
+                child_commands.insert((
+                    Mesh2d(mesh.clone()),
+                    MeshMaterial2d(color_materials.add(ColorMaterial {
+                        alpha_mode: AlphaMode2d::Blend,
+                        ..ColorMaterial::from_color(Color::WHITE)
+                    })),
+                ));
+            }
+            #[cfg(feature = "3d")]
+            if !is_2d {
+                child_commands.insert((
+                    Mesh3d(mesh),
+                    NotShadowCaster,
+                    MeshMaterial3d(standard_materials.add(StandardMaterial {
+                        base_color: Color::WHITE,
+                        alpha_mode: AlphaMode::Blend,
+                        unlit: true,
+                        double_sided: true,
+                        cull_mode: None,
+                        ..default()
+                    })),
+                ));
+            }
+            if let Some(render_layers) = render_layers {
+                child_commands.insert(render_layers.clone());
+            }
+        });
+    }
+}
+
+/// System updating the changed cells of grids with a `GridCellOverlay`,
+/// and keeping the overlay of tracked grids in place while they move
+pub fn grid_cell_overlay_updater(
+    mut query_grid: Query<
+        (
+            Entity,
+            &Grid,
+            &mut GridCellOverlay,
+            Option<&TrackedGrid>,
+            &Transform,
+            &Children,
+        ),
+        Without<GridCellOverlayChild>,
+    >,
+    mut query_children: Query<(&mut GridCellOverlayChild, &mut Transform)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    #[cfg(feature = "2d")] query_2d: Query<(), With<Grid2d>>,
+) {
+    for (entity, grid, mut overlay, tracked, transform, children) in query_grid.iter_mut() {
+        #[cfg(feature = "2d")]
+        let is_2d = query_2d.contains(entity);
+        #[cfg(not(feature = "2d"))]
+        let is_2d = {
+            let _ = entity;
+            false
+        };
+        let alignment = grid_plane_alignment(tracked, is_2d);
+        let translation = overlay_translation(
+            grid,
+            tracked,
+            &alignment,
+            transform.translation,
+            OVERLAY_DEPTH,
+        );
+
+        let mut iter = query_children.iter_many_mut(children);
+        while let Some((mut child, mut child_transform)) = iter.fetch_next() {
+            if child_transform.translation != translation {
+                child_transform.translation = translation;
+            }
+            if overlay.changed.is_empty() {
+                continue;
+            }
+            let Some(mesh) = meshes.get_mut(&child.mesh) else {
+                continue;
+            };
+            let cells = overlay.changed.iter().copied().collect::<Vec<_>>();
+            child.apply(mesh, grid, &alignment, &overlay, cells);
+        }
+        if !overlay.changed.is_empty() {
+            overlay.bypass_change_detection().changed.clear();
+        }
+    }
+}
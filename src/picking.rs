@@ -0,0 +1,152 @@
+#![allow(clippy::type_complexity)]
+
+use bevy::camera::visibility::RenderLayers;
+use bevy::picking::backend::{ray::RayMap, HitData, PointerHits};
+use bevy::picking::pointer::PointerId;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+use crate::*;
+
+/// Hit of a pointer on a grid's plane, resolved into the grid's cell and snapped positions
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GridPickHit {
+    /// World position of the hit on the grid's plane
+    pub position: Vec3,
+    /// Index of the cell containing the hit, see `GridQuery::cell`
+    pub cell: IVec2,
+    /// World position of the hit snapped to the grid's nearest line intersection, see `GridQuery::snap`
+    pub snapped: Vec3,
+    /// World position of the hit snapped to the nearest line intersection of the grid or its `SubGrid`,
+    /// see `GridQuery::snap_to_sub_grid`
+    pub snapped_to_sub_grid: Vec3,
+}
+
+/// Latest hit of each pointer on a grid, updated by the picking backend before `Pointer` events are triggered.
+/// Required by every grid when the `picking` feature is enabled.
+#[derive(Component, Clone, Debug, Default, PartialEq)]
+pub struct GridPickHits(pub HashMap<PointerId, GridPickHit>);
+
+impl GridPickHits {
+    /// Returns the latest hit of a pointer on the grid, if the pointer is over the grid
+    pub fn get(&self, pointer: PointerId) -> Option<&GridPickHit> {
+        self.0.get(&pointer)
+    }
+}
+
+/// Picking backend system, reporting hits on the plane of every visible grid within its extent.
+/// Tracked grids follow their camera, and are hit within the radius they are clipped to around the camera.
+///
+/// Hits are reported on the grid entity, with the world position of the hit on the grid's plane,
+/// and are resolved into a cell and snapped positions in the grid's `GridPickHits`.
+/// Grids with a `Pickable` that is not hoverable, or whose `RenderLayers` do not intersect the camera's, are ignored.
+pub fn update_grid_picking_hits(
+    ray_map: Res<RayMap>,
+    query_camera: Query<(
+        &Camera,
+        &GlobalTransform,
+        Option<&Projection>,
+        Option<&RenderLayers>,
+    )>,
+    query_grid: Query<(
+        Entity,
+        &Grid,
+        Option<&TrackedGrid>,
+        Option<&TrackedGridCount>,
+        Option<&GridFade>,
+        Option<&RenderLayers>,
+        Option<&Pickable>,
+        &GlobalTransform,
+        &InheritedVisibility,
+    )>,
+    mut query_hits: Query<(Entity, &mut GridPickHits)>,
+    grids: GridQuery,
+    #[cfg(feature = "2d")] query_2d: Query<(), With<Grid2d>>,
+    mut writer: MessageWriter<PointerHits>,
+) {
+    let mut grid_hits = HashMap::<Entity, GridPickHits>::default();
+    for (&ray_id, &ray) in ray_map.iter() {
+        let Ok((camera, camera_transform, projection, camera_layers)) =
+            query_camera.get(ray_id.camera)
+        else {
+            continue;
+        };
+        let camera_layers = camera_layers.cloned().unwrap_or_default();
+
+        let picks = query_grid
+            .iter()
+            .filter(|(_, _, _, _, _, layers, pickable, _, visibility)| {
+                visibility.get()
+                    && pickable.is_none_or(|pickable| pickable.is_hoverable)
+                    && camera_layers.intersects(&layers.cloned().unwrap_or_default())
+            })
+            .filter_map(|(entity, grid, tracked, count, fade, _, _, transform, _)| {
+                #[cfg(feature = "2d")]
+                let is_2d = query_2d.contains(entity);
+                #[cfg(not(feature = "2d"))]
+                let is_2d = false;
+                let alignment = grid_plane_alignment(tracked, is_2d);
+                let grid = &tracked_grid_with_count(grid, count);
+                let (_, world, distance) = ray_grid_intersection(
+                    ray,
+                    grid,
+                    &alignment,
+                    transform,
+                    matches!(projection, Some(Projection::Orthographic(_))),
+                )?;
+                // Tracked grids are clipped around the camera, like in the `ClippedLineMaterial`
+                if let Some(tracked) = tracked {
+                    let center = tracked_grid_center(tracked, camera_transform, projection);
+                    let (_, radius) = fade
+                        .copied()
+                        .unwrap_or_default()
+                        .distances(grid.count as f32 * grid.spacing - grid.spacing);
+                    let dist_squared = ((center - world)
+                        * tracked.alignment.to_inverted_axis_vec3())
+                    .length_squared();
+                    if dist_squared > radius * radius {
+                        return None;
+                    }
+                }
+                let normal = transform
+                    .affine()
+                    .transform_vector3(alignment.to_axis_vec3())
+                    .normalize_or_zero();
+                Some((
+                    entity,
+                    HitData::new(ray_id.camera, distance, Some(world), Some(normal)),
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        for (entity, hit) in &picks {
+            let Some(position) = hit.position else {
+                continue;
+            };
+            let (Some(cell), Some(snapped), Some(snapped_to_sub_grid)) = (
+                grids.cell(*entity, position),
+                grids.snap(*entity, position),
+                grids.snap_to_sub_grid(*entity, position),
+            ) else {
+                continue;
+            };
+            grid_hits.entry(*entity).or_default().0.insert(
+                ray_id.pointer,
+                GridPickHit {
+                    position,
+                    cell,
+                    snapped,
+                    snapped_to_sub_grid,
+                },
+            );
+        }
+        if !picks.is_empty() {
+            writer.write(PointerHits::new(ray_id.pointer, picks, camera.order as f32));
+        }
+    }
+
+    // Hits of pointers no longer over a grid are cleared
+    for (entity, mut hits) in query_hits.iter_mut() {
+        hits.set_if_neq(grid_hits.remove(&entity).unwrap_or_default());
+    }
+}
@@ -1,9 +1,15 @@
+#[cfg(any(feature = "3d", feature = "2d"))]
 use bevy::asset::load_internal_asset;
 use bevy::prelude::*;
+#[cfg(feature = "2d")]
+use bevy::sprite_render::Material2dPlugin;
 use std::marker::PhantomData;
 
 use crate::*;
 
+#[cfg(feature = "2d")]
+pub use crate::systems_2d::spawn_floor_grid_2d;
+
 /// Spawns a default floor grid, resembling the one used in [Blender](https://www.blender.org/)
 pub fn spawn_floor_grid(mut commands: Commands) {
     commands.spawn((
@@ -23,14 +29,35 @@ pub fn spawn_floor_grid(mut commands: Commands) {
 /// The plugin which allows floor grids to work, where `T` is the component to track the floor grid to
 pub struct TrackedDebugGridPlugin<T: Component> {
     spawn_floor_grid: bool,
+    #[cfg(feature = "2d")]
+    spawn_floor_grid_2d: bool,
+    #[cfg(feature = "gizmos")]
+    use_gizmos: bool,
     _phantom: PhantomData<T>,
 }
 
 impl<T: Component> TrackedDebugGridPlugin<T> {
-    /// Adds the plugin along with a default floor grid
+    /// Adds the plugin along with a default floor grid.
+    /// With the `2d` feature but without the `3d` feature, the floor grid is a 2D one, unless drawn with gizmos.
     pub const fn with_floor_grid() -> Self {
         Self {
             spawn_floor_grid: true,
+            #[cfg(feature = "2d")]
+            spawn_floor_grid_2d: false,
+            #[cfg(feature = "gizmos")]
+            use_gizmos: false,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Adds the plugin along with a default 2D floor grid
+    #[cfg(feature = "2d")]
+    pub const fn with_floor_grid_2d() -> Self {
+        Self {
+            spawn_floor_grid: false,
+            spawn_floor_grid_2d: true,
+            #[cfg(feature = "gizmos")]
+            use_gizmos: false,
             _phantom: PhantomData,
         }
     }
@@ -39,9 +66,26 @@ impl<T: Component> TrackedDebugGridPlugin<T> {
     pub const fn without_floor_grid() -> Self {
         Self {
             spawn_floor_grid: false,
+            #[cfg(feature = "2d")]
+            spawn_floor_grid_2d: false,
+            #[cfg(feature = "gizmos")]
+            use_gizmos: false,
             _phantom: PhantomData,
         }
     }
+
+    /// Draws every grid through `Gizmos`, as if they were all marked with `GizmoGrid`
+    #[cfg(feature = "gizmos")]
+    pub const fn with_gizmos(mut self) -> Self {
+        self.use_gizmos = true;
+        self
+    }
+}
+
+/// Observer marking every added grid with `GizmoGrid`, used when the plugin draws all grids with gizmos
+#[cfg(feature = "gizmos")]
+fn mark_gizmo_grid(add: On<Add, Grid>, mut commands: Commands) {
+    commands.entity(add.entity).insert(GizmoGrid);
 }
 
 impl<T: Component> Default for TrackedDebugGridPlugin<T> {
@@ -52,34 +96,206 @@ impl<T: Component> Default for TrackedDebugGridPlugin<T> {
 
 impl<T: Component> Plugin for TrackedDebugGridPlugin<T> {
     fn build(&self, app: &mut App) {
-        load_internal_asset!(
-            app,
-            CLIPPED_LINE_SHADER_HANDLE,
-            "shaders/clipped_line.wgsl",
-            Shader::from_wgsl
-        );
-        load_internal_asset!(
-            app,
-            SIMPLE_LINE_SHADER_HANDLE,
-            "shaders/simple_line.wgsl",
-            Shader::from_wgsl
-        );
+        #[cfg(feature = "3d")]
+        {
+            load_internal_asset!(
+                app,
+                CLIPPED_LINE_SHADER_HANDLE,
+                "shaders/clipped_line.wgsl",
+                Shader::from_wgsl
+            );
+            load_internal_asset!(
+                app,
+                SIMPLE_LINE_SHADER_HANDLE,
+                "shaders/simple_line.wgsl",
+                Shader::from_wgsl
+            );
+            load_internal_asset!(
+                app,
+                CHECKERBOARD_SHADER_HANDLE,
+                "shaders/checkerboard.wgsl",
+                Shader::from_wgsl
+            );
 
-        app.add_plugins((
-            MaterialPlugin::<SimpleLineMaterial>::default(),
-            MaterialPlugin::<ClippedLineMaterial>::default(),
-        ))
-        .add_systems(
+            app.add_plugins((
+                MaterialPlugin::<SimpleLineMaterial>::default(),
+                MaterialPlugin::<ClippedLineMaterial>::default(),
+                MaterialPlugin::<CheckerboardMaterial>::default(),
+            ))
+            .add_systems(
+                PreUpdate,
+                (
+                    main_grid_mesher_untracked,
+                    main_grid_mesher_tracked,
+                    sub_grid_mesher,
+                    grid_axis_mesher,
+                    grid_fill_mesher,
+                    grid_volume_mesher,
+                ),
+            )
+            .add_systems(
+                PostUpdate,
+                untracked_grid_fade_center_updater.after(TransformSystems::Propagate),
+            );
+        }
+        #[cfg(feature = "2d")]
+        {
+            load_internal_asset!(
+                app,
+                CLIPPED_LINE_2D_SHADER_HANDLE,
+                "shaders/clipped_line_2d.wgsl",
+                Shader::from_wgsl
+            );
+            load_internal_asset!(
+                app,
+                SIMPLE_LINE_2D_SHADER_HANDLE,
+                "shaders/simple_line_2d.wgsl",
+                Shader::from_wgsl
+            );
+
+            app.add_plugins((
+                Material2dPlugin::<SimpleLineMaterial2d>::default(),
+                Material2dPlugin::<ClippedLineMaterial2d>::default(),
+            ))
+            .add_systems(
+                PreUpdate,
+                (main_grid_mesher_2d, sub_grid_mesher_2d, grid_axis_mesher_2d),
+            )
+            .add_systems(
+                PostUpdate,
+                untracked_grid_fade_center_updater_2d.after(TransformSystems::Propagate),
+            );
+            if self.spawn_floor_grid_2d {
+                app.add_systems(Startup, spawn_floor_grid_2d);
+            }
+        }
+
+        #[cfg(feature = "gizmos")]
+        {
+            app.init_gizmo_group::<DebugGridGizmos>().add_systems(
+                PostUpdate,
+                (draw_gizmo_grids::<T>, draw_gizmo_grid_volumes).after(TransformSystems::Propagate),
+            );
+            if self.use_gizmos {
+                app.add_observer(mark_gizmo_grid);
+            }
+        }
+
+        #[cfg(feature = "hover")]
+        {
+            app.add_message::<HoveredGridCell>().add_systems(
+                PostUpdate,
+                hovered_grid_cell_updater::<T>.before(TransformSystems::Propagate),
+            );
+            #[cfg(any(feature = "3d", feature = "2d"))]
+            app.add_systems(PreUpdate, grid_cell_highlight_mesher)
+                .add_systems(
+                    PostUpdate,
+                    grid_cell_highlight_updater
+                        .after(hovered_grid_cell_updater::<T>)
+                        .before(TransformSystems::Propagate),
+                );
+            #[cfg(feature = "gizmos")]
+            app.add_systems(
+                PostUpdate,
+                draw_gizmo_cell_highlight
+                    .after(hovered_grid_cell_updater::<T>)
+                    .after(TransformSystems::Propagate),
+            );
+        }
+
+        #[cfg(any(feature = "3d", feature = "2d"))]
+        app.add_systems(
             PreUpdate,
             (
-                main_grid_mesher_untracked,
-                main_grid_mesher_tracked,
-                sub_grid_mesher,
-                grid_axis_mesher,
-                tracked_grid_updater::<T>,
-                custom_tracked_grid_updater,
+                grid_cell_overlay_mesher,
+                (grid_heatmap_image_watcher, grid_heatmap_mesher).chain(),
             ),
         )
+        .add_systems(
+            PostUpdate,
+            (grid_cell_overlay_updater, grid_heatmap_updater).before(TransformSystems::Propagate),
+        )
+        .add_systems(
+            Update,
+            (
+                despawn_children_upon_removal::<Grid, GridCellOverlayChild>,
+                despawn_children_upon_removal::<GridCellOverlay, GridCellOverlayChild>,
+                despawn_children_upon_removal::<Grid, GridHeatmapChild>,
+                despawn_children_upon_removal::<GridHeatmap, GridHeatmapChild>,
+                remesh_upon_removal::<GridFade, Grid>,
+            ),
+        );
+
+        #[cfg(feature = "picking")]
+        app.register_required_components::<Grid, GridPickHits>()
+            .add_systems(
+                PreUpdate,
+                update_grid_picking_hits.in_set(bevy::picking::PickingSystems::Backend),
+            );
+
+        #[cfg(feature = "labels")]
+        app.add_systems(PreUpdate, grid_labels_spawner)
+            .add_systems(
+                PostUpdate,
+                (
+                    grid_labels_updater::<T>,
+                    grid_label_layout,
+                    #[cfg(any(feature = "3d", feature = "2d"))]
+                    grid_label_mesher,
+                )
+                    .chain()
+                    .before(TransformSystems::Propagate),
+            )
+            .add_systems(
+                Update,
+                (
+                    despawn_children_upon_removal::<Grid, GridLabel>,
+                    despawn_children_upon_removal::<GridLabels, GridLabel>,
+                ),
+            );
+
+        #[cfg(feature = "measure")]
+        {
+            app.add_systems(PreUpdate, grid_measurement_label_spawner)
+                .add_systems(
+                    PostUpdate,
+                    (
+                        draw_grid_measurements.after(TransformSystems::Propagate),
+                        grid_measurement_label_updater::<T>.before(bevy::ui::UiSystems::Prepare),
+                    ),
+                );
+            #[cfg(feature = "picking")]
+            app.add_observer(grid_measurement_on_click);
+        }
+
+        #[cfg(feature = "orientation")]
+        app.add_message::<OrientationSnapped>()
+            .add_systems(PreUpdate, orientation_gizmo_spawner)
+            .add_systems(Update, orientation_gizmo_click)
+            .add_systems(
+                PostUpdate,
+                orientation_gizmo_updater.after(TransformSystems::Propagate),
+            );
+
+        #[cfg(feature = "rulers")]
+        app.add_systems(PreUpdate, grid_rulers_spawner).add_systems(
+            PostUpdate,
+            grid_rulers_updater::<T>.before(bevy::ui::UiSystems::Prepare),
+        );
+
+        app.add_message::<CellEntered>()
+            .add_message::<CellExited>()
+            .init_resource::<GridOccupancy>()
+            .add_systems(
+                PostUpdate,
+                grid_occupancy_updater.after(TransformSystems::Propagate),
+            );
+
+        app.add_systems(
+            PreUpdate,
+            (tracked_grid_updater::<T>, custom_tracked_grid_updater),
+        )
         .add_systems(
             Update,
             (
@@ -90,10 +306,39 @@ impl<T: Component> Plugin for TrackedDebugGridPlugin<T> {
                 despawn_children_upon_removal::<GridAxis, GridAxisChild>,
             ),
         );
+        #[cfg(feature = "3d")]
+        app.add_systems(
+            Update,
+            (
+                despawn_children_upon_removal::<Grid, GridFillChild>,
+                despawn_children_upon_removal::<GridFill, GridFillChild>,
+                remesh_upon_removal::<GridFill, Grid>,
+                despawn_children_upon_removal::<GridVolume, GridVolumeChild>,
+                remesh_upon_removal::<GridOcclusion, Grid>,
+                remesh_upon_removal::<GridAntialiasing, Grid>,
+                remesh_upon_removal::<GridShading, Grid>,
+                remesh_upon_removal::<GridHeightColor, Grid>,
+                remesh_upon_removal::<GridHeightColor, GridVolume>,
+            ),
+        );
         if self.spawn_floor_grid {
-            app.add_systems(Startup, spawn_floor_grid);
+            // Without the `3d` feature, meshes only render the floor grid in 2D
+            #[cfg(feature = "gizmos")]
+            let renders_3d = cfg!(feature = "3d") || !cfg!(feature = "2d") || self.use_gizmos;
+            #[cfg(not(feature = "gizmos"))]
+            let renders_3d = cfg!(feature = "3d") || !cfg!(feature = "2d");
+            if renders_3d {
+                app.add_systems(Startup, spawn_floor_grid);
+            } else {
+                #[cfg(feature = "2d")]
+                app.add_systems(Startup, spawn_floor_grid_2d);
+            }
         }
     }
 }
 
 pub type DebugGridPlugin = TrackedDebugGridPlugin<Camera>;
+
+/// The plugin tracking grids to the 2D camera
+#[cfg(feature = "2d")]
+pub type DebugGrid2dPlugin = TrackedDebugGridPlugin<Camera2d>;
@@ -1,31 +1,230 @@
-use bevy::asset::uuid_handle;
+use bevy::asset::{uuid_handle, RenderAssetUsages};
 #[allow(unused_imports)]
 use bevy::{
     asset::{Asset, Handle},
-    pbr::{MaterialPipeline, MaterialPipelineKey},
+    mesh::{
+        Indices, MeshVertexAttribute, MeshVertexBufferLayoutRef, PrimitiveTopology, VertexFormat,
+    },
+    pbr::{MaterialPipeline, MaterialPipelineKey, MeshPipelineKey},
     prelude::*,
-    mesh::MeshVertexBufferLayoutRef,
-    shader::ShaderRef,
     render::{
         render_asset::RenderAssets,
         render_resource::{
-            AsBindGroup, AsBindGroupShaderType, PolygonMode, RenderPipelineDescriptor,
-            ShaderType, SpecializedMeshPipelineError,
+            AsBindGroup, AsBindGroupShaderType, CompareFunction, PolygonMode,
+            RenderPipelineDescriptor, ShaderType, SpecializedMeshPipelineError,
         },
         texture::GpuImage,
     },
+    shader::ShaderRef,
 };
 
 /// Handle for the clipped line shader
 pub const CLIPPED_LINE_SHADER_HANDLE: Handle<Shader> =
     uuid_handle!("66CF2528-BE11-4875-9A37-218FB089E67D");
 
-use crate::{GridAlignment, GridAxis};
+use crate::{
+    GridAlignment, GridAntialiasing, GridAxis, GridFade, GridOcclusion, GridShading, GridStyle,
+    LineStyle, LineUnits,
+};
+
+/// Vertex attribute holding the distance of a line's vertex along the line,
+/// measured from where the line meets the plane through the mesh's origin perpendicular to it.
+/// The line materials use it to draw the `LineStyle` of lines in world units.
+pub const ATTRIBUTE_LINE_DISTANCE: MeshVertexAttribute =
+    MeshVertexAttribute::new("LineDistance", 0x6C1B_9F3A, VertexFormat::Float32);
+
+/// Returns the distance of every vertex of a line list along its line, for the `ATTRIBUTE_LINE_DISTANCE`
+pub fn line_distances(vertices: &[Vec3]) -> Vec<f32> {
+    vertices
+        .chunks_exact(2)
+        .flat_map(|line| {
+            let direction = (line[1] - line[0]).normalize_or_zero();
+            [line[0].dot(direction), line[1].dot(direction)]
+        })
+        .collect()
+}
+
+/// Creates a line list mesh, along with the `ATTRIBUTE_LINE_DISTANCE` of its vertices
+pub fn line_list_mesh(vertices: Vec<Vec3>) -> Mesh {
+    let distances = line_distances(&vertices);
+    Mesh::new(PrimitiveTopology::LineList, RenderAssetUsages::all())
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, vertices)
+        .with_inserted_attribute(ATTRIBUTE_LINE_DISTANCE, distances)
+}
+
+/// Vertex attribute holding the other end of the line a vertex belongs to, for lines drawn as quads
+pub const ATTRIBUTE_LINE_OTHER_END: MeshVertexAttribute =
+    MeshVertexAttribute::new("LineOtherEnd", 0x6C1B_9F3B, VertexFormat::Float32x3);
+
+/// Vertex attribute holding how a vertex of a line drawn as a quad is expanded on screen.
+/// The first component is the side of the line, `-1.0` or `1.0`, and the second is `1.0` for the line's end, `0.0` for its start.
+pub const ATTRIBUTE_LINE_EXPANSION: MeshVertexAttribute =
+    MeshVertexAttribute::new("LineExpansion", 0x6C1B_9F3C, VertexFormat::Float32x2);
+
+/// Creates a triangle list mesh drawing each line of a line list as a quad,
+/// expanded on screen by the line materials to the width of a `GridAntialiasing`
+pub fn antialiased_line_mesh(vertices: Vec<Vec3>) -> Mesh {
+    let distances = line_distances(&vertices);
+    let mut positions = Vec::with_capacity(vertices.len() * 2);
+    let mut other_ends = Vec::with_capacity(vertices.len() * 2);
+    let mut expansions = Vec::with_capacity(vertices.len() * 2);
+    let mut quad_distances = Vec::with_capacity(vertices.len() * 2);
+    let mut indices = Vec::with_capacity(vertices.len() * 3);
+    for (index, (line, distances)) in vertices
+        .chunks_exact(2)
+        .zip(distances.chunks_exact(2))
+        .enumerate()
+    {
+        // Sides are flipped at the end, where the line's direction on screen is reversed
+        positions.extend([line[0], line[0], line[1], line[1]]);
+        other_ends.extend([line[1], line[1], line[0], line[0]]);
+        expansions.extend([[1.0_f32, 0.0], [-1.0, 0.0], [-1.0, 1.0], [1.0, 1.0]]);
+        quad_distances.extend([distances[0], distances[0], distances[1], distances[1]]);
+        let first = index as u32 * 4;
+        indices.extend([first, first + 1, first + 2, first + 2, first + 1, first + 3]);
+    }
+    Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::all())
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_attribute(ATTRIBUTE_LINE_DISTANCE, quad_distances)
+        .with_inserted_attribute(ATTRIBUTE_LINE_OTHER_END, other_ends)
+        .with_inserted_attribute(ATTRIBUTE_LINE_EXPANSION, expansions)
+        .with_inserted_indices(Indices::U32(indices))
+}
+
+/// Creates the mesh of a line list, drawn as quads when the grid has a `GridAntialiasing`
+pub fn line_mesh(vertices: Vec<Vec3>, antialiasing: Option<&GridAntialiasing>) -> Mesh {
+    match antialiasing {
+        Some(_) => antialiased_line_mesh(vertices),
+        None => line_list_mesh(vertices),
+    }
+}
+
+/// Creates the mesh of a grid's markers, drawn filled as triangles when the `GridStyle` is filled,
+/// and as lines otherwise, as quads when the grid has a `GridAntialiasing`
+pub fn marker_mesh(
+    vertices: Vec<Vec3>,
+    style: GridStyle,
+    antialiasing: Option<&GridAntialiasing>,
+) -> Mesh {
+    if style.is_filled() {
+        Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::all())
+            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, vertices)
+    } else {
+        line_mesh(vertices, antialiasing)
+    }
+}
+
+/// Returns the alpha mode of materials that need blending, such as anti-aliased lines blending their edges.
+/// Blended materials are also left out of the depth prepass.
+fn blended_alpha_mode(alpha_mode: AlphaMode) -> AlphaMode {
+    match alpha_mode {
+        AlphaMode::Opaque | AlphaMode::Mask(_) | AlphaMode::AlphaToCoverage => AlphaMode::Blend,
+        alpha_mode => alpha_mode,
+    }
+}
+
+/// Pipeline key of the line materials
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LineMaterialKey {
+    /// Whether hidden lines are drawn, disabling the depth test
+    x_ray: bool,
+}
+
+impl From<Option<GridOcclusion>> for LineMaterialKey {
+    fn from(occlusion: Option<GridOcclusion>) -> Self {
+        Self {
+            x_ray: occlusion.is_some_and(|occlusion| occlusion.x_ray),
+        }
+    }
+}
+
+/// Specializes the pipeline of a line material, drawing lines as such,
+/// and disabling the depth test in x-ray when the view has a depth prepass for the shader to test against.
+/// Line distances are passed to the shader when the mesh has an `ATTRIBUTE_LINE_DISTANCE`,
+/// meshes with an `ATTRIBUTE_LINE_EXPANSION` are drawn as quads expanded by the shader,
+/// and other triangle meshes are the filled markers of a `GridStyle`, drawn as such.
+fn specialize_line_pipeline(
+    descriptor: &mut RenderPipelineDescriptor,
+    layout: &MeshVertexBufferLayoutRef,
+    mesh_key: MeshPipelineKey,
+    key: LineMaterialKey,
+) -> Result<(), SpecializedMeshPipelineError> {
+    let mut attributes = vec![Mesh::ATTRIBUTE_POSITION.at_shader_location(0)];
+    if layout.0.contains(ATTRIBUTE_LINE_DISTANCE) {
+        attributes.push(ATTRIBUTE_LINE_DISTANCE.at_shader_location(1));
+        descriptor.vertex.shader_defs.push("LINE_DISTANCE".into());
+        if let Some(fragment) = descriptor.fragment.as_mut() {
+            fragment.shader_defs.push("LINE_DISTANCE".into());
+        }
+    }
+    let expanded = layout.0.contains(ATTRIBUTE_LINE_EXPANSION);
+    if expanded {
+        attributes.push(ATTRIBUTE_LINE_OTHER_END.at_shader_location(2));
+        attributes.push(ATTRIBUTE_LINE_EXPANSION.at_shader_location(3));
+        descriptor.vertex.shader_defs.push("LINE_EXPANSION".into());
+        if let Some(fragment) = descriptor.fragment.as_mut() {
+            fragment.shader_defs.push("LINE_EXPANSION".into());
+        }
+    }
+    let filled = !expanded && mesh_key.primitive_topology() == PrimitiveTopology::TriangleList;
+    if expanded || filled {
+        // Quads wind either way depending on the line's direction on screen, and markers are seen from both sides
+        descriptor.primitive.cull_mode = None;
+    }
+    descriptor.vertex.buffers = vec![layout.0.get_layout(&attributes)?];
+
+    #[cfg(not(target_arch = "wasm32"))]
+    if !expanded && !filled {
+        descriptor.primitive.polygon_mode = PolygonMode::Line;
+    }
+    if key.x_ray && mesh_key.contains(MeshPipelineKey::DEPTH_PREPASS) {
+        if let Some(depth_stencil) = descriptor.depth_stencil.as_mut() {
+            depth_stencil.depth_compare = CompareFunction::Always;
+            depth_stencil.depth_write_enabled = false;
+        }
+    }
+    Ok(())
+}
+
+/// Returns the style fields of the line material uniforms, `(dash, gap, units)`
+fn line_style_uniform(line_style: LineStyle) -> (f32, f32, u32) {
+    let (dash, gap) = line_style.dash_gap();
+    (dash, gap, (line_style.units() == LineUnits::Screen) as u32)
+}
+
+/// Returns the occlusion fields of the line material uniforms, `(soft_intersection, x_ray, x_ray_alpha, x_ray_dash)`
+fn occlusion_uniform(occlusion: Option<GridOcclusion>) -> (f32, u32, f32, f32) {
+    match occlusion {
+        Some(occlusion) => (
+            occlusion.soft_intersection.max(0.0_f32),
+            occlusion.x_ray as u32,
+            occlusion.x_ray_alpha,
+            occlusion.x_ray_dash.max(f32::EPSILON),
+        ),
+        None => (0.0_f32, 0, 1.0_f32, 1.0_f32),
+    }
+}
+
+/// Returns the shading fields of the grid material uniforms, `(fog, fog_scattering, exposure, tonemapping)`
+fn shading_uniform(shading: Option<GridShading>) -> (u32, u32, f32, u32) {
+    match shading {
+        Some(shading) => (
+            shading.fog as u32,
+            shading.fog_scattering as u32,
+            shading.exposure.clamp(0.0_f32, 1.0_f32),
+            shading.tonemapping as u32,
+        ),
+        None => (0, 0, 0.0_f32, 0),
+    }
+}
 
 /// Material used for tracked grids.
 /// It will clip beyond a certain distance from the camera, creating the illusion of an infinite grid.
+/// With an orthographic projection, the distance is measured from where the view direction meets the grid's plane,
+/// and fading only starts beyond the visible area.
 #[derive(AsBindGroup, Asset, TypePath, Debug, Clone)]
 #[uniform(0, ClippedLineMaterialUniform)]
+#[bind_group_data(LineMaterialKey)]
 pub struct ClippedLineMaterial {
     pub color: Color,
     pub alpha_mode: AlphaMode,
@@ -35,6 +234,26 @@ pub struct ClippedLineMaterial {
     pub x_axis_color: Color,
     pub y_axis_color: Color,
     pub z_axis_color: Color,
+    pub negative_axis_alpha: f32,
+    pub fade_start: f32,
+    pub fade: GridFade,
+    pub occlusion: Option<GridOcclusion>,
+    pub line_style: LineStyle,
+    pub axis_line_style: LineStyle,
+    /// Emissive intensities multiplying the colors of the lines, and of the axis lines
+    pub intensity: f32,
+    pub axis_intensity: f32,
+    pub shading: Option<GridShading>,
+    /// Width of anti-aliased lines in physical pixels, `0.0` for hardware lines
+    pub line_width: f32,
+    /// Bias of the sorting distance, drawing blended materials over others at the same depth
+    pub depth_bias: f32,
+}
+
+impl From<&ClippedLineMaterial> for LineMaterialKey {
+    fn from(material: &ClippedLineMaterial) -> Self {
+        material.occlusion.into()
+    }
 }
 
 impl ClippedLineMaterial {
@@ -49,6 +268,9 @@ impl ClippedLineMaterial {
         let x_axis_color = axis.and_then(|axis| axis.x).unwrap_or(color);
         let y_axis_color = axis.and_then(|axis| axis.y).unwrap_or(color);
         let z_axis_color = axis.and_then(|axis| axis.z).unwrap_or(color);
+        let negative_axis_alpha = axis.map_or(1.0_f32, |axis| axis.negative_alpha);
+        let axis_line_style = axis.map_or(LineStyle::Solid, |axis| axis.line_style);
+        let axis_intensity = axis.map_or(1.0_f32, |axis| axis.intensity);
         Self {
             color,
             alpha_mode,
@@ -58,8 +280,63 @@ impl ClippedLineMaterial {
             x_axis_color,
             y_axis_color,
             z_axis_color,
+            negative_axis_alpha,
+            fade_start: radius * GridFade::DEFAULT_START_RATIO,
+            fade: GridFade::default(),
+            occlusion: None,
+            line_style: LineStyle::Solid,
+            axis_line_style,
+            intensity: 1.0_f32,
+            axis_intensity,
+            shading: None,
+            line_width: 0.0_f32,
+            depth_bias: 0.0_f32,
         }
     }
+
+    /// Applies a `GridFade`, the current `radius` being its default end
+    pub fn with_fade(mut self, fade: Option<&GridFade>) -> Self {
+        self.fade = fade.copied().unwrap_or_default();
+        (self.fade_start, self.radius) = self.fade.distances(self.radius);
+        self
+    }
+
+    /// Applies a `GridOcclusion`
+    pub fn with_occlusion(mut self, occlusion: Option<&GridOcclusion>) -> Self {
+        self.occlusion = occlusion.copied();
+        self
+    }
+
+    /// Applies a `LineStyle` to the lines, axis lines following the style of the `GridAxis` instead
+    pub fn with_line_style(mut self, line_style: LineStyle) -> Self {
+        self.line_style = line_style;
+        self
+    }
+
+    /// Sets the emissive intensity multiplying the color of the lines, axis lines keeping the intensity of the `GridAxis`
+    pub fn with_intensity(mut self, intensity: f32) -> Self {
+        self.intensity = intensity;
+        self
+    }
+
+    /// Applies a `GridShading`
+    pub fn with_shading(mut self, shading: Option<&GridShading>) -> Self {
+        self.shading = shading.copied();
+        self
+    }
+
+    /// Applies a `GridAntialiasing`, for meshes drawing lines as quads
+    pub fn with_antialiasing(mut self, antialiasing: Option<&GridAntialiasing>) -> Self {
+        self.line_width =
+            antialiasing.map_or(0.0_f32, |antialiasing| antialiasing.line_width.max(0.0_f32));
+        self
+    }
+
+    /// Sets the bias of the sorting distance, blended lines with a higher bias being drawn over others at the same depth
+    pub fn with_depth_bias(mut self, depth_bias: f32) -> Self {
+        self.depth_bias = depth_bias;
+        self
+    }
 }
 
 /// Uniform for the `ClippedLineMaterial`
@@ -72,6 +349,28 @@ pub struct ClippedLineMaterialUniform {
     pub x_axis_color: LinearRgba,
     pub y_axis_color: LinearRgba,
     pub z_axis_color: LinearRgba,
+    pub negative_axis_alpha: f32,
+    pub fade_start: f32,
+    pub fade_curve: u32,
+    pub grazing_attenuation: f32,
+    pub keep_axis_unfaded: u32,
+    pub soft_intersection: f32,
+    pub x_ray: u32,
+    pub x_ray_alpha: f32,
+    pub x_ray_dash: f32,
+    pub dash: f32,
+    pub gap: f32,
+    pub line_units: u32,
+    pub axis_dash: f32,
+    pub axis_gap: f32,
+    pub axis_line_units: u32,
+    pub axis_intensity: f32,
+    pub line_width: f32,
+    pub intensity: f32,
+    pub fog: u32,
+    pub fog_scattering: u32,
+    pub exposure: f32,
+    pub tonemapping: u32,
 }
 
 impl AsBindGroupShaderType<ClippedLineMaterialUniform> for ClippedLineMaterial {
@@ -79,6 +378,10 @@ impl AsBindGroupShaderType<ClippedLineMaterialUniform> for ClippedLineMaterial {
         &self,
         _images: &RenderAssets<GpuImage>,
     ) -> ClippedLineMaterialUniform {
+        let (soft_intersection, x_ray, x_ray_alpha, x_ray_dash) = occlusion_uniform(self.occlusion);
+        let (dash, gap, line_units) = line_style_uniform(self.line_style);
+        let (axis_dash, axis_gap, axis_line_units) = line_style_uniform(self.axis_line_style);
+        let (fog, fog_scattering, exposure, tonemapping) = shading_uniform(self.shading);
         ClippedLineMaterialUniform {
             color: self.color.into(),
             alignment: self.alignment.into(),
@@ -87,28 +390,61 @@ impl AsBindGroupShaderType<ClippedLineMaterialUniform> for ClippedLineMaterial {
             x_axis_color: self.x_axis_color.into(),
             y_axis_color: self.y_axis_color.into(),
             z_axis_color: self.z_axis_color.into(),
+            negative_axis_alpha: self.negative_axis_alpha,
+            fade_start: self.fade_start,
+            fade_curve: self.fade.curve.shader_index(),
+            grazing_attenuation: self.fade.grazing_attenuation,
+            keep_axis_unfaded: self.fade.keep_axis_unfaded as u32,
+            soft_intersection,
+            x_ray,
+            x_ray_alpha,
+            x_ray_dash,
+            dash,
+            gap,
+            line_units,
+            axis_dash,
+            axis_gap,
+            axis_line_units,
+            axis_intensity: self.axis_intensity.max(0.0_f32),
+            line_width: self.line_width,
+            intensity: self.intensity.max(0.0_f32),
+            fog,
+            fog_scattering,
+            exposure,
+            tonemapping,
         }
     }
 }
 
 impl Material for ClippedLineMaterial {
+    fn vertex_shader() -> ShaderRef {
+        CLIPPED_LINE_SHADER_HANDLE.into()
+    }
+
     fn fragment_shader() -> ShaderRef {
         CLIPPED_LINE_SHADER_HANDLE.into()
     }
 
+    // Lines with a `GridOcclusion` blend, keeping them out of the depth prepass they sample
     fn alpha_mode(&self) -> AlphaMode {
-        self.alpha_mode
+        if self.line_width > 0.0_f32 || self.occlusion.is_some() {
+            blended_alpha_mode(self.alpha_mode)
+        } else {
+            self.alpha_mode
+        }
+    }
+
+    fn depth_bias(&self) -> f32 {
+        self.depth_bias
     }
 
-    #[cfg(not(target_arch = "wasm32"))]
     fn specialize(
         _pipeline: &MaterialPipeline,
         descriptor: &mut RenderPipelineDescriptor,
-        _layout: &MeshVertexBufferLayoutRef,
-        _key: MaterialPipelineKey<Self>,
+        layout: &MeshVertexBufferLayoutRef,
+        key: MaterialPipelineKey<Self>,
     ) -> Result<(), SpecializedMeshPipelineError> {
-        descriptor.primitive.polygon_mode = PolygonMode::Line;
-        Ok(())
+        specialize_line_pipeline(descriptor, layout, key.mesh_key, key.bind_group_data)
     }
 }
 
@@ -116,51 +452,386 @@ impl Material for ClippedLineMaterial {
 pub const SIMPLE_LINE_SHADER_HANDLE: Handle<Shader> =
     uuid_handle!("3E41FD75-3AEA-4B8A-B2CE-6AE5A32973F4");
 
-/// Simple line material with no functionality beyond assigning a color
-#[derive(Default, Asset, AsBindGroup, TypePath, Debug, Clone)]
+/// Simple line material with no functionality beyond assigning a color, and an optional distance fade and clipping
+#[derive(Asset, AsBindGroup, TypePath, Debug, Clone)]
+#[uniform(0, SimpleLineMaterialUniform)]
+#[bind_group_data(LineMaterialKey)]
 pub struct SimpleLineMaterial {
-    #[uniform(0)]
     pub color: LinearRgba,
     pub alpha_mode: AlphaMode,
+    /// Fade with the distance to the camera, with explicit `start` and `end` distances
+    pub fade: Option<GridFade>,
+    /// World position the fade is measured from instead of the camera, maintained for a `FadeCenter::GridOrigin`
+    pub fade_center: Option<Vec3>,
+    pub occlusion: Option<GridOcclusion>,
+    pub line_style: LineStyle,
+    /// Emissive intensity multiplying the color
+    pub intensity: f32,
+    pub shading: Option<GridShading>,
+    /// Width of anti-aliased lines in physical pixels, `0.0` for hardware lines
+    pub line_width: f32,
+    /// Bias of the sorting distance, drawing blended materials over others at the same depth
+    pub depth_bias: f32,
+}
+
+impl From<&SimpleLineMaterial> for LineMaterialKey {
+    fn from(material: &SimpleLineMaterial) -> Self {
+        material.occlusion.into()
+    }
+}
+
+impl Default for SimpleLineMaterial {
+    fn default() -> Self {
+        Self::from_linear_rgba(LinearRgba::default(), AlphaMode::default())
+    }
 }
 
 impl SimpleLineMaterial {
     /// Construct a `SimpleLineMaterial` from a `LinearRgba` and an `AlphaMode`
     pub const fn from_linear_rgba(color: LinearRgba, alpha_mode: AlphaMode) -> Self {
-        Self { color, alpha_mode }
+        Self {
+            color,
+            alpha_mode,
+            fade: None,
+            fade_center: None,
+            occlusion: None,
+            line_style: LineStyle::Solid,
+            intensity: 1.0_f32,
+            shading: None,
+            line_width: 0.0_f32,
+            depth_bias: 0.0_f32,
+        }
     }
 
     /// Construct a `SimpleLineMaterial` from a `Color` and an `AlphaMode`
     pub fn from_color(color: Color, alpha_mode: AlphaMode) -> Self {
-        Self {
-            color: color.into(),
-            alpha_mode,
-        }
+        Self::from_linear_rgba(color.into(), alpha_mode)
     }
 
     /// Set the color using a `Color` instead of an `LinearRgba`
     pub fn set_color(&mut self, color: Color) {
         self.color = color.into();
     }
+
+    /// Fades lines out with the distance to the camera or to the `fade_center`, following a `GridFade` with `radius` being its default end.
+    /// Lines do not fade without a `GridFade`.
+    pub fn with_fade(mut self, fade: Option<&GridFade>, radius: f32) -> Self {
+        self.fade = fade.map(|fade| {
+            let (start, end) = fade.distances(radius);
+            GridFade {
+                start: Some(start),
+                end: Some(end),
+                ..*fade
+            }
+        });
+        self
+    }
+
+    /// Applies a `GridOcclusion`
+    pub fn with_occlusion(mut self, occlusion: Option<&GridOcclusion>) -> Self {
+        self.occlusion = occlusion.copied();
+        self
+    }
+
+    /// Applies a `LineStyle` to the lines
+    pub fn with_line_style(mut self, line_style: LineStyle) -> Self {
+        self.line_style = line_style;
+        self
+    }
+
+    /// Sets the emissive intensity multiplying the color of the lines
+    pub fn with_intensity(mut self, intensity: f32) -> Self {
+        self.intensity = intensity;
+        self
+    }
+
+    /// Applies a `GridShading`
+    pub fn with_shading(mut self, shading: Option<&GridShading>) -> Self {
+        self.shading = shading.copied();
+        self
+    }
+
+    /// Applies a `GridAntialiasing`, for meshes drawing lines as quads
+    pub fn with_antialiasing(mut self, antialiasing: Option<&GridAntialiasing>) -> Self {
+        self.line_width =
+            antialiasing.map_or(0.0_f32, |antialiasing| antialiasing.line_width.max(0.0_f32));
+        self
+    }
+
+    /// Sets the bias of the sorting distance, blended lines with a higher bias being drawn over others at the same depth
+    pub fn with_depth_bias(mut self, depth_bias: f32) -> Self {
+        self.depth_bias = depth_bias;
+        self
+    }
+}
+
+/// Uniform for the `SimpleLineMaterial`
+#[derive(Clone, Default, ShaderType)]
+pub struct SimpleLineMaterialUniform {
+    pub color: LinearRgba,
+    /// Fade distances, a negative end disabling the fade
+    pub fade_start: f32,
+    pub fade_end: f32,
+    pub fade_curve: u32,
+    /// Point the fade is measured from, when `fade_from_center` is non-zero
+    pub fade_center: Vec3,
+    pub fade_from_center: u32,
+    pub soft_intersection: f32,
+    pub x_ray: u32,
+    pub x_ray_alpha: f32,
+    pub x_ray_dash: f32,
+    pub dash: f32,
+    pub gap: f32,
+    pub line_units: u32,
+    pub line_width: f32,
+    pub intensity: f32,
+    pub fog: u32,
+    pub fog_scattering: u32,
+    pub exposure: f32,
+    pub tonemapping: u32,
+}
+
+impl AsBindGroupShaderType<SimpleLineMaterialUniform> for SimpleLineMaterial {
+    fn as_bind_group_shader_type(
+        &self,
+        _images: &RenderAssets<GpuImage>,
+    ) -> SimpleLineMaterialUniform {
+        let (fade_start, fade_end, fade_curve) = match self.fade {
+            Some(fade) => {
+                let (start, end) = fade.distances(0.0_f32);
+                (start, end, fade.curve.shader_index())
+            }
+            None => (0.0_f32, -1.0_f32, 0),
+        };
+        let (soft_intersection, x_ray, x_ray_alpha, x_ray_dash) = occlusion_uniform(self.occlusion);
+        let (dash, gap, line_units) = line_style_uniform(self.line_style);
+        let (fog, fog_scattering, exposure, tonemapping) = shading_uniform(self.shading);
+        SimpleLineMaterialUniform {
+            color: self.color,
+            fade_start,
+            fade_end,
+            fade_curve,
+            fade_center: self.fade_center.unwrap_or_default(),
+            fade_from_center: self.fade_center.is_some() as u32,
+            soft_intersection,
+            x_ray,
+            x_ray_alpha,
+            x_ray_dash,
+            dash,
+            gap,
+            line_units,
+            line_width: self.line_width,
+            intensity: self.intensity.max(0.0_f32),
+            fog,
+            fog_scattering,
+            exposure,
+            tonemapping,
+        }
+    }
 }
 
 impl Material for SimpleLineMaterial {
+    fn vertex_shader() -> ShaderRef {
+        SIMPLE_LINE_SHADER_HANDLE.into()
+    }
+
     fn fragment_shader() -> ShaderRef {
         SIMPLE_LINE_SHADER_HANDLE.into()
     }
 
+    // Lines with a `GridOcclusion` blend, keeping them out of the depth prepass they sample
+    fn alpha_mode(&self) -> AlphaMode {
+        if self.line_width > 0.0_f32 || self.occlusion.is_some() {
+            blended_alpha_mode(self.alpha_mode)
+        } else {
+            self.alpha_mode
+        }
+    }
+
+    fn depth_bias(&self) -> f32 {
+        self.depth_bias
+    }
+
+    fn specialize(
+        _pipeline: &MaterialPipeline,
+        descriptor: &mut RenderPipelineDescriptor,
+        layout: &MeshVertexBufferLayoutRef,
+        key: MaterialPipelineKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        specialize_line_pipeline(descriptor, layout, key.mesh_key, key.bind_group_data)
+    }
+}
+
+/// Handle for the checkerboard shader
+pub const CHECKERBOARD_SHADER_HANDLE: Handle<Shader> =
+    uuid_handle!("8D1C2F4E-5B7A-4E3D-9C60-1A2B3C4D5E6F");
+
+/// Material filling the cells of a grid with alternating colors, for a `GridFill::Checkerboard`.
+/// Cells are counted from the UVs of the mesh, which hold its local coordinates in cells,
+/// or from the world's origin when `world_locked`.
+#[derive(Asset, AsBindGroup, TypePath, Debug, Clone)]
+#[uniform(0, CheckerboardMaterialUniform)]
+pub struct CheckerboardMaterial {
+    pub colors: [Color; 2],
+    pub alpha_mode: AlphaMode,
+    pub alignment: GridAlignment,
+    pub spacing: f32,
+    /// Whether cells are counted from the world's origin, keeping the checkerboard of tracked grids in place
+    pub world_locked: bool,
+    /// Fade with the distance to the camera, with explicit `start` and `end` distances.
+    /// World locked checkerboards measure the distance within the grid's plane.
+    pub fade: Option<GridFade>,
+    /// World position the fade is measured from instead of the camera, maintained for a `FadeCenter::GridOrigin`
+    pub fade_center: Option<Vec3>,
+    pub shading: Option<GridShading>,
+    /// Bias of the sorting distance, drawing blended materials over others at the same depth
+    pub depth_bias: f32,
+}
+
+impl CheckerboardMaterial {
+    /// Creates a checkerboard of cells spaced by `spacing` on a plane, blending when either color is translucent
+    pub fn new(colors: [Color; 2], alignment: GridAlignment, spacing: f32) -> Self {
+        let alpha_mode = if colors.iter().all(|color| color.alpha() >= 1.0_f32) {
+            AlphaMode::Opaque
+        } else {
+            AlphaMode::Blend
+        };
+        Self {
+            colors,
+            alpha_mode,
+            alignment,
+            spacing,
+            world_locked: false,
+            fade: None,
+            fade_center: None,
+            shading: None,
+            depth_bias: 0.0_f32,
+        }
+    }
+
+    /// Counts cells from the world's origin, for tracked grids
+    pub fn world_locked(mut self) -> Self {
+        self.world_locked = true;
+        self
+    }
+
+    /// Fades the checkerboard out with the distance to the camera, following a `GridFade` with `radius` being its default end.
+    /// Fading needs blending, opaque checkerboards being blended instead.
+    pub fn with_fade(mut self, fade: Option<&GridFade>, radius: f32) -> Self {
+        self.fade = fade.map(|fade| {
+            let (start, end) = fade.distances(radius);
+            GridFade {
+                start: Some(start),
+                end: Some(end),
+                ..*fade
+            }
+        });
+        if self.fade.is_some() {
+            self.alpha_mode = blended_alpha_mode(self.alpha_mode);
+        }
+        self
+    }
+
+    /// Blends the checkerboard of a grid with a `GridOcclusion`,
+    /// keeping it out of the depth prepass the grid's lines fade against
+    pub fn with_occlusion(mut self, occlusion: Option<&GridOcclusion>) -> Self {
+        if occlusion.is_some() {
+            self.alpha_mode = blended_alpha_mode(self.alpha_mode);
+        }
+        self
+    }
+
+    /// Applies a `GridShading`
+    pub fn with_shading(mut self, shading: Option<&GridShading>) -> Self {
+        self.shading = shading.copied();
+        self
+    }
+
+    /// Sets the bias of the sorting distance, a lower bias drawing the checkerboard under lines at the same depth
+    pub fn with_depth_bias(mut self, depth_bias: f32) -> Self {
+        self.depth_bias = depth_bias;
+        self
+    }
+}
+
+/// Uniform for the `CheckerboardMaterial`
+#[derive(Clone, Default, ShaderType)]
+pub struct CheckerboardMaterialUniform {
+    pub first_color: LinearRgba,
+    pub second_color: LinearRgba,
+    /// Axis of the grid's plane, along which world locked cells are counted
+    pub first_axis: Vec3,
+    pub spacing: f32,
+    pub second_axis: Vec3,
+    pub world_locked: u32,
+    /// Fade distances, a negative end disabling the fade
+    pub fade_start: f32,
+    pub fade_end: f32,
+    pub fade_curve: u32,
+    /// Point the fade is measured from, when `fade_from_center` is non-zero
+    pub fade_center: Vec3,
+    pub fade_from_center: u32,
+    pub fog: u32,
+    pub fog_scattering: u32,
+    pub exposure: f32,
+    pub tonemapping: u32,
+}
+
+impl AsBindGroupShaderType<CheckerboardMaterialUniform> for CheckerboardMaterial {
+    fn as_bind_group_shader_type(
+        &self,
+        _images: &RenderAssets<GpuImage>,
+    ) -> CheckerboardMaterialUniform {
+        let (fade_start, fade_end, fade_curve) = match self.fade {
+            Some(fade) => {
+                let (start, end) = fade.distances(0.0_f32);
+                (start, end, fade.curve.shader_index())
+            }
+            None => (0.0_f32, -1.0_f32, 0),
+        };
+        let [first_axis, second_axis] = self.alignment.plane_axis();
+        let (fog, fog_scattering, exposure, tonemapping) = shading_uniform(self.shading);
+        CheckerboardMaterialUniform {
+            first_color: self.colors[0].into(),
+            second_color: self.colors[1].into(),
+            first_axis: first_axis.to_axis_vec3(),
+            spacing: self.spacing,
+            second_axis: second_axis.to_axis_vec3(),
+            world_locked: self.world_locked as u32,
+            fade_start,
+            fade_end,
+            fade_curve,
+            fade_center: self.fade_center.unwrap_or_default(),
+            fade_from_center: self.fade_center.is_some() as u32,
+            fog,
+            fog_scattering,
+            exposure,
+            tonemapping,
+        }
+    }
+}
+
+impl Material for CheckerboardMaterial {
+    fn fragment_shader() -> ShaderRef {
+        CHECKERBOARD_SHADER_HANDLE.into()
+    }
+
     fn alpha_mode(&self) -> AlphaMode {
         self.alpha_mode
     }
 
-    #[cfg(not(target_arch = "wasm32"))]
+    fn depth_bias(&self) -> f32 {
+        self.depth_bias
+    }
+
     fn specialize(
         _pipeline: &MaterialPipeline,
         descriptor: &mut RenderPipelineDescriptor,
         _layout: &MeshVertexBufferLayoutRef,
         _key: MaterialPipelineKey<Self>,
     ) -> Result<(), SpecializedMeshPipelineError> {
-        descriptor.primitive.polygon_mode = PolygonMode::Line;
+        // The fill is seen from both sides of the grid
+        descriptor.primitive.cull_mode = None;
         Ok(())
     }
 }
@@ -0,0 +1,247 @@
+use bevy::asset::uuid_handle;
+#[allow(unused_imports)]
+use bevy::{
+    asset::{Asset, Handle},
+    mesh::{MeshVertexBufferLayoutRef, PrimitiveTopology},
+    prelude::*,
+    render::{
+        render_asset::RenderAssets,
+        render_resource::{
+            AsBindGroup, AsBindGroupShaderType, PolygonMode, RenderPipelineDescriptor, ShaderType,
+            SpecializedMeshPipelineError,
+        },
+        texture::GpuImage,
+    },
+    shader::ShaderRef,
+    sprite_render::{AlphaMode2d, Material2d, Material2dKey, Mesh2dPipelineKey},
+};
+
+use crate::{GridAxis, GridFade};
+
+/// Draws the lines of a 2D line material as such, triangle meshes being the filled markers of a `GridStyle`
+#[cfg(not(target_arch = "wasm32"))]
+fn specialize_line_pipeline_2d(
+    descriptor: &mut RenderPipelineDescriptor,
+    mesh_key: Mesh2dPipelineKey,
+) {
+    if mesh_key.primitive_topology() != PrimitiveTopology::TriangleList {
+        descriptor.primitive.polygon_mode = PolygonMode::Line;
+    }
+}
+
+/// Converts an `AlphaMode` to the closest `AlphaMode2d`.
+/// Blending modes unsupported in 2D fall back to `AlphaMode2d::Blend`.
+pub const fn alpha_mode_2d(alpha_mode: AlphaMode) -> AlphaMode2d {
+    match alpha_mode {
+        AlphaMode::Opaque => AlphaMode2d::Opaque,
+        AlphaMode::Mask(threshold) => AlphaMode2d::Mask(threshold),
+        _ => AlphaMode2d::Blend,
+    }
+}
+
+/// Handle for the 2D clipped line shader
+pub const CLIPPED_LINE_2D_SHADER_HANDLE: Handle<Shader> =
+    uuid_handle!("0F5A6C3E-6E0B-4F3A-9C1B-5B8D7E2A4C61");
+
+/// 2D counterpart of the `ClippedLineMaterial`, used for tracked 2D grids.
+/// It will clip beyond the visible area of the camera, creating the illusion of an infinite grid.
+#[derive(AsBindGroup, Asset, TypePath, Debug, Clone)]
+#[uniform(0, ClippedLineMaterial2dUniform)]
+pub struct ClippedLineMaterial2d {
+    pub color: Color,
+    pub alpha_mode: AlphaMode2d,
+    pub radius: f32,
+    pub x_axis_color: Color,
+    pub y_axis_color: Color,
+    pub negative_axis_alpha: f32,
+    pub fade_start: f32,
+    pub fade: GridFade,
+}
+
+impl ClippedLineMaterial2d {
+    pub fn new(
+        color: Color,
+        alpha_mode: AlphaMode2d,
+        radius: f32,
+        axis: Option<&GridAxis>,
+    ) -> Self {
+        let x_axis_color = axis.and_then(|axis| axis.x).unwrap_or(color);
+        let y_axis_color = axis.and_then(|axis| axis.y).unwrap_or(color);
+        let negative_axis_alpha = axis.map_or(1.0_f32, |axis| axis.negative_alpha);
+        Self {
+            color,
+            alpha_mode,
+            radius,
+            x_axis_color,
+            y_axis_color,
+            negative_axis_alpha,
+            fade_start: radius * GridFade::DEFAULT_START_RATIO,
+            fade: GridFade::default(),
+        }
+    }
+
+    /// Applies a `GridFade`, the current `radius` being its default end.
+    /// Its grazing-angle attenuation has no effect in 2D.
+    pub fn with_fade(mut self, fade: Option<&GridFade>) -> Self {
+        self.fade = fade.copied().unwrap_or_default();
+        (self.fade_start, self.radius) = self.fade.distances(self.radius);
+        self
+    }
+}
+
+/// Uniform for the `ClippedLineMaterial2d`
+#[derive(Clone, Default, ShaderType)]
+pub struct ClippedLineMaterial2dUniform {
+    pub color: LinearRgba,
+    pub radius: f32,
+    pub x_axis_color: LinearRgba,
+    pub y_axis_color: LinearRgba,
+    pub negative_axis_alpha: f32,
+    pub fade_start: f32,
+    pub fade_curve: u32,
+}
+
+impl AsBindGroupShaderType<ClippedLineMaterial2dUniform> for ClippedLineMaterial2d {
+    fn as_bind_group_shader_type(
+        &self,
+        _images: &RenderAssets<GpuImage>,
+    ) -> ClippedLineMaterial2dUniform {
+        ClippedLineMaterial2dUniform {
+            color: self.color.into(),
+            radius: self.radius,
+            x_axis_color: self.x_axis_color.into(),
+            y_axis_color: self.y_axis_color.into(),
+            negative_axis_alpha: self.negative_axis_alpha,
+            fade_start: self.fade_start,
+            fade_curve: self.fade.curve.shader_index(),
+        }
+    }
+}
+
+impl Material2d for ClippedLineMaterial2d {
+    fn fragment_shader() -> ShaderRef {
+        CLIPPED_LINE_2D_SHADER_HANDLE.into()
+    }
+
+    fn alpha_mode(&self) -> AlphaMode2d {
+        self.alpha_mode
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn specialize(
+        descriptor: &mut RenderPipelineDescriptor,
+        _layout: &MeshVertexBufferLayoutRef,
+        key: Material2dKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        specialize_line_pipeline_2d(descriptor, key.mesh_key);
+        Ok(())
+    }
+}
+
+/// Handle for the 2D simple line shader
+pub const SIMPLE_LINE_2D_SHADER_HANDLE: Handle<Shader> =
+    uuid_handle!("B7E2D0A4-31C6-4E59-8F0D-9A6C2B5E1F38");
+
+/// 2D counterpart of the `SimpleLineMaterial`, with no functionality beyond assigning a color, and an optional distance fade and clipping
+#[derive(Default, Asset, AsBindGroup, TypePath, Debug, Clone)]
+#[uniform(0, SimpleLineMaterial2dUniform)]
+pub struct SimpleLineMaterial2d {
+    pub color: LinearRgba,
+    pub alpha_mode: AlphaMode2d,
+    /// Fade with the distance to the camera on the XY plane, with explicit `start` and `end` distances
+    pub fade: Option<GridFade>,
+    /// World position the fade is measured from instead of the camera, maintained for a `FadeCenter::GridOrigin`
+    pub fade_center: Option<Vec3>,
+}
+
+impl SimpleLineMaterial2d {
+    /// Construct a `SimpleLineMaterial2d` from a `LinearRgba` and an `AlphaMode2d`
+    pub const fn from_linear_rgba(color: LinearRgba, alpha_mode: AlphaMode2d) -> Self {
+        Self {
+            color,
+            alpha_mode,
+            fade: None,
+            fade_center: None,
+        }
+    }
+
+    /// Construct a `SimpleLineMaterial2d` from a `Color` and an `AlphaMode2d`
+    pub fn from_color(color: Color, alpha_mode: AlphaMode2d) -> Self {
+        Self::from_linear_rgba(color.into(), alpha_mode)
+    }
+
+    /// Set the color using a `Color` instead of an `LinearRgba`
+    pub fn set_color(&mut self, color: Color) {
+        self.color = color.into();
+    }
+
+    /// Fades lines out with the distance to the camera or to the `fade_center`, following a `GridFade` with `radius` being its default end.
+    /// Lines do not fade without a `GridFade`.
+    pub fn with_fade(mut self, fade: Option<&GridFade>, radius: f32) -> Self {
+        self.fade = fade.map(|fade| {
+            let (start, end) = fade.distances(radius);
+            GridFade {
+                start: Some(start),
+                end: Some(end),
+                ..*fade
+            }
+        });
+        self
+    }
+}
+
+/// Uniform for the `SimpleLineMaterial2d`
+#[derive(Clone, Default, ShaderType)]
+pub struct SimpleLineMaterial2dUniform {
+    pub color: LinearRgba,
+    /// Fade distances, a negative end disabling the fade
+    pub fade_start: f32,
+    pub fade_end: f32,
+    pub fade_curve: u32,
+    /// Point the fade is measured from, when `fade_from_center` is non-zero
+    pub fade_center: Vec2,
+    pub fade_from_center: u32,
+}
+
+impl AsBindGroupShaderType<SimpleLineMaterial2dUniform> for SimpleLineMaterial2d {
+    fn as_bind_group_shader_type(
+        &self,
+        _images: &RenderAssets<GpuImage>,
+    ) -> SimpleLineMaterial2dUniform {
+        let (fade_start, fade_end, fade_curve) = match self.fade {
+            Some(fade) => {
+                let (start, end) = fade.distances(0.0_f32);
+                (start, end, fade.curve.shader_index())
+            }
+            None => (0.0_f32, -1.0_f32, 0),
+        };
+        SimpleLineMaterial2dUniform {
+            color: self.color,
+            fade_start,
+            fade_end,
+            fade_curve,
+            fade_center: self.fade_center.unwrap_or_default().truncate(),
+            fade_from_center: self.fade_center.is_some() as u32,
+        }
+    }
+}
+
+impl Material2d for SimpleLineMaterial2d {
+    fn fragment_shader() -> ShaderRef {
+        SIMPLE_LINE_2D_SHADER_HANDLE.into()
+    }
+
+    fn alpha_mode(&self) -> AlphaMode2d {
+        self.alpha_mode
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn specialize(
+        descriptor: &mut RenderPipelineDescriptor,
+        _layout: &MeshVertexBufferLayoutRef,
+        key: Material2dKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        specialize_line_pipeline_2d(descriptor, key.mesh_key);
+        Ok(())
+    }
+}
@@ -0,0 +1,424 @@
+#![allow(clippy::type_complexity)]
+
+use bevy::color::palettes::tailwind;
+use bevy::prelude::*;
+use bevy::transform::helper::TransformHelper;
+use bevy::ui::UiTargetCamera;
+
+use crate::*;
+
+/// Adds rulers along the top and left edges of the tracked camera's viewport,
+/// showing the world coordinates of the grid's plane under each edge.
+/// Spawn it next to a tracked grid (`TrackedGrid`) for it to have effect.
+///
+/// Ticks are aligned to the lines of the grid, and to the lines of its `SubGrid` when present.
+/// Ticks and labels too close to each other on screen are skipped.
+#[derive(Component, Clone, Debug)]
+pub struct GridRulers {
+    /// Thickness of the rulers, in logical pixels
+    pub thickness: f32,
+    /// Font size of the coordinates
+    pub font_size: f32,
+    /// Color of the ticks and coordinates
+    pub color: Color,
+    /// Background color of the rulers
+    pub background_color: Color,
+    /// Decimal count of the coordinates, derived from the grid's spacing when `None`
+    pub precision: Option<usize>,
+}
+
+impl GridRulers {
+    pub const DEFAULT_SRGBA: Srgba = tailwind::GRAY_300;
+    pub const DEFAULT_BACKGROUND_SRGBA: Srgba = tailwind::GRAY_900;
+
+    /// Minimum distance between two ticks, in logical pixels
+    pub const MIN_TICK_GAP: f32 = 4.0_f32;
+    /// Maximum tick count considered per ruler, beyond which sub-grid ticks, then all ticks, are skipped
+    pub const MAX_TICKS: usize = 1024;
+}
+
+impl Default for GridRulers {
+    fn default() -> Self {
+        Self {
+            thickness: 24.0_f32,
+            font_size: 10.0_f32,
+            color: Color::Srgba(Self::DEFAULT_SRGBA),
+            background_color: Color::Srgba(Self::DEFAULT_BACKGROUND_SRGBA.with_alpha(0.8_f32)),
+            precision: None,
+        }
+    }
+}
+
+/// The viewport edge along which a ruler is placed
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RulerEdge {
+    #[default]
+    Top,
+    Left,
+}
+
+/// A ruler spawned by `GridRulers`.
+/// Rulers are UI nodes, and are thus not children of their grid.
+#[derive(Component, Clone, Debug)]
+pub struct GridRuler {
+    /// The grid entity this ruler belongs to
+    pub grid: Entity,
+    /// The viewport edge this ruler is placed along
+    pub edge: RulerEdge,
+}
+
+/// Marker component for the tick nodes of a `GridRuler`
+#[derive(Component)]
+pub struct RulerTick;
+
+/// Marker component for the coordinate text nodes of a `GridRuler`
+#[derive(Component)]
+pub struct RulerLabel;
+
+/// A tick to place on a ruler
+struct Tick {
+    /// Position along the ruler, in logical pixels
+    position: f32,
+    /// World coordinate along the ruler's axis
+    coordinate: f32,
+    /// Whether the tick is aligned to a line of the main grid
+    major: bool,
+}
+
+/// System spawning the rulers of grids with `GridRulers`
+pub fn grid_rulers_spawner(
+    mut commands: Commands,
+    query_grid: Query<(Entity, &GridRulers), Changed<GridRulers>>,
+    query_rulers: Query<(Entity, &GridRuler)>,
+) {
+    for (entity, rulers) in query_grid.iter() {
+        for (ruler_entity, ruler) in query_rulers.iter() {
+            if ruler.grid == entity {
+                commands.entity(ruler_entity).despawn();
+            }
+        }
+
+        for edge in [RulerEdge::Top, RulerEdge::Left] {
+            let node = match edge {
+                RulerEdge::Top => Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(0.0_f32),
+                    top: Val::Px(0.0_f32),
+                    width: Val::Percent(100.0_f32),
+                    height: Val::Px(rulers.thickness),
+                    overflow: Overflow::clip(),
+                    ..default()
+                },
+                // The top ruler covers the corner
+                RulerEdge::Left => Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(0.0_f32),
+                    top: Val::Px(rulers.thickness),
+                    width: Val::Px(rulers.thickness),
+                    bottom: Val::Px(0.0_f32),
+                    overflow: Overflow::clip(),
+                    ..default()
+                },
+            };
+            commands.spawn((
+                GridRuler { grid: entity, edge },
+                node,
+                BackgroundColor(rulers.background_color),
+                Visibility::Hidden,
+            ));
+        }
+    }
+}
+
+/// Returns the intersection of a ray with the plane of a tracked grid, if it hits the plane in front of the ray.
+/// Planes behind the ray's origin are hit by `orthographic` rays, as an orthographic projection sees them.
+fn intersect_grid_plane(ray: Ray3d, tracked: &TrackedGrid, orthographic: bool) -> Option<Vec3> {
+    let normal = tracked.alignment.to_axis_vec3();
+    let facing = ray.direction.dot(normal);
+    if facing.abs() < f32::EPSILON {
+        return None;
+    }
+    let distance = (tracked.offset - ray.origin.dot(normal)) / facing;
+    (distance > 0.0_f32 || orthographic).then(|| ray.get_point(distance))
+}
+
+/// Returns the ticks of a ruler, given the grid plane's points under both ends of the ruler
+fn ruler_ticks(
+    (camera, camera_transform): (&Camera, &GlobalTransform),
+    edge: RulerEdge,
+    (start, end): (Vec3, Vec3),
+    axis: Vec3,
+    (spacing, sub_count): (f32, usize),
+) -> Vec<Tick> {
+    let (from, to) = (start.dot(axis), end.dot(axis));
+    let (min, max) = (from.min(to), from.max(to));
+    let divisions = (sub_count + 1) as f32;
+    let (step, divisions) = if (max - min) * divisions / spacing < GridRulers::MAX_TICKS as f32 {
+        (spacing / divisions, sub_count as i64 + 1)
+    } else if (max - min) / spacing < GridRulers::MAX_TICKS as f32 {
+        (spacing, 1)
+    } else {
+        return Vec::new();
+    };
+
+    ((min / step).ceil() as i64..=(max / step).floor() as i64)
+        .filter_map(|index| {
+            let coordinate = index as f32 * step;
+            let point = start.lerp(end, (coordinate - from) / (to - from));
+            let viewport_position = camera.world_to_viewport(camera_transform, point).ok()?;
+            let position = match edge {
+                RulerEdge::Top => viewport_position.x,
+                RulerEdge::Left => viewport_position.y,
+            };
+            Some(Tick {
+                position,
+                coordinate,
+                major: index % divisions == 0,
+            })
+        })
+        .collect()
+}
+
+/// System placing the ticks and coordinates of grid rulers, as seen by the camera tracked by the component `T`,
+/// or by the `tracking_override` of a `TrackedGrid`.
+/// Rulers of grids that no longer exist, or no longer have `GridRulers`, are despawned.
+#[allow(clippy::too_many_arguments)]
+pub fn grid_rulers_updater<T: Component>(
+    mut commands: Commands,
+    mut query_rulers: Query<(
+        Entity,
+        &GridRuler,
+        &mut Visibility,
+        Option<&UiTargetCamera>,
+        Option<&Children>,
+    )>,
+    mut query_ticks: Query<&mut Node, (With<RulerTick>, Without<RulerLabel>)>,
+    mut query_labels: Query<(&mut Node, &mut Text), (With<RulerLabel>, Without<RulerTick>)>,
+    query_grid: Query<(
+        &Grid,
+        &GridRulers,
+        &TrackedGrid,
+        Option<&SubGrid>,
+        &InheritedVisibility,
+    )>,
+    query_camera: Query<(&Camera, Option<&Projection>)>,
+    query_tracked: Query<Entity, (With<T>, With<Camera>, TrackedEntityFilter)>,
+    transform_helper: TransformHelper,
+) {
+    for (entity, ruler, mut visibility, target_camera, children) in query_rulers.iter_mut() {
+        let Ok((grid, rulers, tracked, sub_grid, grid_visibility)) = query_grid.get(ruler.grid)
+        else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        let camera_entity = tracked
+            .tracking_override
+            .filter(|entity| query_camera.contains(*entity))
+            .or_else(|| query_tracked.single().ok());
+        let Some((camera_entity, (camera, projection))) = camera_entity
+            .and_then(|entity| Some((entity, query_camera.get(entity).ok()?)))
+            .filter(|_| grid_visibility.get())
+        else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        let (Ok(camera_transform), Some(viewport_size)) = (
+            transform_helper.compute_global_transform(camera_entity),
+            camera.logical_viewport_size(),
+        ) else {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        visibility.set_if_neq(Visibility::Inherited);
+        if target_camera.is_none_or(|target_camera| target_camera.0 != camera_entity) {
+            commands
+                .entity(entity)
+                .insert(UiTargetCamera(camera_entity));
+        }
+
+        // The grid's plane under both ends of the ruler's edge
+        let (start, end, length) = match ruler.edge {
+            RulerEdge::Top => (
+                Vec2::ZERO,
+                Vec2::new(viewport_size.x, 0.0_f32),
+                viewport_size.x,
+            ),
+            RulerEdge::Left => (
+                Vec2::ZERO,
+                Vec2::new(0.0_f32, viewport_size.y),
+                viewport_size.y,
+            ),
+        };
+        let plane_point = |viewport_position: Vec2| {
+            camera
+                .viewport_to_world(&camera_transform, viewport_position)
+                .ok()
+                .and_then(|ray| {
+                    intersect_grid_plane(
+                        ray,
+                        tracked,
+                        matches!(projection, Some(Projection::Orthographic(_))),
+                    )
+                })
+        };
+        let mut ticks = match (plane_point(start), plane_point(end)) {
+            (Some(start), Some(end)) => {
+                // The ruler follows the in-plane axis changing the most along its edge
+                let axis = tracked
+                    .alignment
+                    .plane_axis()
+                    .map(|axis| axis.to_axis_vec3())
+                    .into_iter()
+                    .max_by(|a, b| {
+                        (end - start)
+                            .dot(*a)
+                            .abs()
+                            .total_cmp(&(end - start).dot(*b).abs())
+                    })
+                    .unwrap_or(Vec3::X);
+                ruler_ticks(
+                    (camera, &camera_transform),
+                    ruler.edge,
+                    (start, end),
+                    axis,
+                    (grid.spacing, sub_grid.map_or(0, |sub_grid| sub_grid.count)),
+                )
+            }
+            // The edge lies beyond the horizon
+            _ => Vec::new(),
+        };
+        ticks.retain(|tick| (0.0_f32..=length).contains(&tick.position));
+        ticks.sort_by(|a, b| a.position.total_cmp(&b.position));
+
+        let precision = rulers
+            .precision
+            .unwrap_or_else(|| coordinate_precision(grid.spacing));
+        let tick_entities = children
+            .into_iter()
+            .flatten()
+            .filter(|child| query_ticks.contains(**child))
+            .copied()
+            .collect::<Vec<_>>();
+        let mut tick_entities = tick_entities.into_iter();
+        let label_entities = children
+            .into_iter()
+            .flatten()
+            .filter(|child| query_labels.contains(**child))
+            .copied()
+            .collect::<Vec<_>>();
+        let mut label_entities = label_entities.into_iter();
+        let (mut last_tick, mut last_label) = (f32::NEG_INFINITY, f32::NEG_INFINITY);
+        let (mut missing_ticks, mut missing_labels) = (0, 0);
+        for tick in ticks {
+            if tick.position - last_tick < GridRulers::MIN_TICK_GAP {
+                continue;
+            }
+            last_tick = tick.position;
+            let tick_length = rulers.thickness * if tick.major { 0.5_f32 } else { 0.25_f32 };
+            let tick_node = match ruler.edge {
+                RulerEdge::Top => Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(tick.position),
+                    bottom: Val::Px(0.0_f32),
+                    width: Val::Px(1.0_f32),
+                    height: Val::Px(tick_length),
+                    ..default()
+                },
+                RulerEdge::Left => Node {
+                    position_type: PositionType::Absolute,
+                    // The left ruler starts below the top one
+                    top: Val::Px(tick.position - rulers.thickness),
+                    right: Val::Px(0.0_f32),
+                    width: Val::Px(tick_length),
+                    height: Val::Px(1.0_f32),
+                    ..default()
+                },
+            };
+            match tick_entities.next() {
+                Some(child) => {
+                    if let Ok(mut node) = query_ticks.get_mut(child) {
+                        node.set_if_neq(tick_node);
+                    }
+                }
+                None => missing_ticks += 1,
+            }
+
+            // Coordinates are only shown at major ticks, given enough room for their text
+            let coordinate = format!("{:.*}", precision, tick.coordinate);
+            let label_length = rulers.font_size * 0.6_f32 * coordinate.len() as f32 + 8.0_f32;
+            if !tick.major || tick.position - last_label < label_length {
+                continue;
+            }
+            last_label = tick.position;
+            let label_node = match ruler.edge {
+                RulerEdge::Top => Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(tick.position + 2.0_f32),
+                    top: Val::Px(0.0_f32),
+                    ..default()
+                },
+                RulerEdge::Left => Node {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(tick.position - rulers.thickness + 2.0_f32),
+                    left: Val::Px(0.0_f32),
+                    ..default()
+                },
+            };
+            match label_entities.next() {
+                Some(child) => {
+                    if let Ok((mut node, mut text)) = query_labels.get_mut(child) {
+                        node.set_if_neq(label_node);
+                        if text.0 != coordinate {
+                            text.0 = coordinate;
+                        }
+                    }
+                }
+                None => missing_labels += 1,
+            }
+        }
+
+        // Unused ticks and labels are kept for later frames, but hidden
+        for child in tick_entities {
+            if let Ok(mut node) = query_ticks.get_mut(child) {
+                if node.display != Display::None {
+                    node.display = Display::None;
+                }
+            }
+        }
+        for child in label_entities {
+            if let Ok((mut node, _)) = query_labels.get_mut(child) {
+                if node.display != Display::None {
+                    node.display = Display::None;
+                }
+            }
+        }
+        // Missing ticks and labels are placed from the next frame on
+        if missing_ticks == 0 && missing_labels == 0 {
+            continue;
+        }
+        commands.entity(entity).with_children(|children| {
+            for _ in 0..missing_ticks {
+                children.spawn((
+                    RulerTick,
+                    Node {
+                        display: Display::None,
+                        ..default()
+                    },
+                    BackgroundColor(rulers.color),
+                ));
+            }
+            for _ in 0..missing_labels {
+                children.spawn((
+                    RulerLabel,
+                    Node {
+                        display: Display::None,
+                        ..default()
+                    },
+                    Text::default(),
+                    TextFont::from_font_size(rulers.font_size),
+                    TextColor(rulers.color),
+                ));
+            }
+        });
+    }
+}
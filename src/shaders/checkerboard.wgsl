@@ -0,0 +1,135 @@
+#import bevy_pbr::fog::{atmospheric_fog, exponential_fog, exponential_squared_fog, linear_fog}
+#import bevy_pbr::forward_io::VertexOutput
+#import bevy_pbr::mesh_view_bindings::{fog, lights, view}
+#import bevy_pbr::mesh_view_types::{FOG_MODE_ATMOSPHERIC, FOG_MODE_EXPONENTIAL, FOG_MODE_EXPONENTIAL_SQUARED, FOG_MODE_LINEAR}
+#ifdef TONEMAP_IN_SHADER
+#import bevy_core_pipeline::tonemapping::tone_mapping
+#endif
+
+// Fraction of the clipping radius an orthographic fade starts before at the latest, keeping a smooth border
+const MAX_FADE_START: f32 = 0.95;
+
+struct CheckerboardMaterial {
+    first_color: vec4<f32>,
+    second_color: vec4<f32>,
+    first_axis: vec3<f32>,
+    spacing: f32,
+    second_axis: vec3<f32>,
+    world_locked: u32,
+    fade_start: f32,
+    fade_end: f32,
+    fade_curve: u32,
+    fade_center: vec3<f32>,
+    fade_from_center: u32,
+    fog: u32,
+    fog_scattering: u32,
+    exposure: f32,
+    tonemapping: u32,
+};
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0)
+var<uniform> material: CheckerboardMaterial;
+
+// Alpha multiplier of a `FadeCurve` at a distance, fading out from `start` to `end`
+fn fade_curve(dist: f32, start: f32, end: f32) -> f32 {
+    if material.fade_curve == 3u {
+        // Eased over the squared distance
+        return 1.0 - smoothstep(start * start, max(end * end, start * start + 0.0001), dist * dist);
+    }
+    let progress = clamp((dist - start) / max(end - start, 0.0001), 0.0, 1.0);
+    if material.fade_curve == 0u {
+        return 1.0 - progress;
+    } else if material.fade_curve == 2u {
+        return pow(1.0 - progress, 3.0);
+    }
+    return 1.0 - smoothstep(0.0, 1.0, progress);
+}
+
+fn is_orthographic() -> bool {
+    return view.clip_from_view[3].w == 1.0;
+}
+
+// Applies the view's fog, exposure and tonemapping to a color, following the grid's `GridShading`
+fn shade(input_color: vec4<f32>, world_position: vec3<f32>) -> vec4<f32> {
+    var color = vec4(input_color.rgb * mix(1.0, view.exposure, material.exposure), input_color.a);
+#ifdef DISTANCE_FOG
+    if material.fog != 0u {
+        color = apply_grid_fog(color, world_position);
+    }
+#endif
+#ifdef TONEMAP_IN_SHADER
+    if material.tonemapping != 0u {
+        color = tone_mapping(color, view.color_grading);
+    }
+#endif
+    return color;
+}
+
+// Applies the view's `DistanceFog` like lit materials do, without the shadows of the fog's inscattering
+fn apply_grid_fog(color: vec4<f32>, world_position: vec3<f32>) -> vec4<f32> {
+    let view_to_world = world_position - view.world_position.xyz;
+    let distance = length(view_to_world);
+    var scattering = vec3(0.0);
+    if material.fog_scattering != 0u && fog.directional_light_color.a > 0.0 {
+        let direction = view_to_world / distance;
+        for (var i = 0u; i < lights.n_directional_lights; i += 1u) {
+            let light = lights.directional_lights[i];
+            scattering += pow(max(dot(direction, light.direction_to_light), 0.0), fog.directional_light_exponent) * light.color.rgb * view.exposure;
+        }
+    }
+    if fog.mode == FOG_MODE_LINEAR {
+        return linear_fog(fog, color, distance, scattering);
+    } else if fog.mode == FOG_MODE_EXPONENTIAL {
+        return exponential_fog(fog, color, distance, scattering);
+    } else if fog.mode == FOG_MODE_EXPONENTIAL_SQUARED {
+        return exponential_squared_fog(fog, color, distance, scattering);
+    } else if fog.mode == FOG_MODE_ATMOSPHERIC {
+        return atmospheric_fog(fog, color, distance, scattering);
+    }
+    return color;
+}
+
+@fragment
+fn fragment(
+    mesh: VertexOutput,
+) -> @location(0) vec4<f32> {
+    // Cells are counted from the mesh's UVs, or from the world's origin to stay in place while the grid moves by whole cells
+    var cell = floor(mesh.uv);
+    if material.world_locked != 0u {
+        let position = mesh.world_position.xyz / material.spacing;
+        cell = floor(vec2(dot(position, material.first_axis), dot(position, material.second_axis)));
+    }
+    var color = select(material.second_color, material.first_color, fract((cell.x + cell.y) * 0.5) < 0.25);
+
+    // Fade with the distance to the camera or to the fade's center, within the grid's plane when world locked
+    if material.fade_end >= 0.0 {
+        var center = select(view.world_position.xyz, material.fade_center, material.fade_from_center != 0u);
+        var fade_start = material.fade_start;
+        if material.world_locked != 0u && is_orthographic() {
+            // The camera's position is meaningless for an orthographic projection,
+            // distances are measured from where the view direction meets the grid's plane instead, like the lines
+            let normal = cross(material.first_axis, material.second_axis);
+            let forward = normalize(-view.world_from_view[2].xyz);
+            let facing = dot(forward, normal);
+            if abs(facing) > 0.001 {
+                center += forward * (dot(mesh.world_position.xyz - center, normal) / facing);
+            }
+
+            // Only fade beyond the visible area, which stretches across the plane when viewed at an angle
+            let extents = (vec2(1.0) + abs(view.clip_from_view[3].xy)) / vec2(view.clip_from_view[0].x, view.clip_from_view[1].y);
+            let visible_radius = length(extents) / max(abs(facing), 0.1);
+            fade_start = min(max(visible_radius, fade_start), material.fade_end * MAX_FADE_START);
+        }
+        var offset = center - mesh.world_position.xyz;
+        if material.world_locked != 0u {
+            offset = material.first_axis * dot(offset, material.first_axis) + material.second_axis * dot(offset, material.second_axis);
+        }
+        let dist = length(offset);
+        if dist > material.fade_end {
+            discard;
+        }
+        color.a *= fade_curve(dist, fade_start, material.fade_end);
+    }
+
+    return shade(color, mesh.world_position.xyz);
+}
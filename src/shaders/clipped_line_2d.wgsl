@@ -0,0 +1,67 @@
+#import bevy_sprite::mesh2d_vertex_output::VertexOutput
+#import bevy_sprite::mesh2d_view_bindings::view
+
+const LOW: f32 = 0.001;
+const HIGH: f32 = 0.002;
+
+// Fraction of the clipping radius the fade starts before at the latest, keeping a smooth border
+const MAX_FADE_START: f32 = 0.95;
+
+struct LineMaterial {
+    color: vec4<f32>,
+    radius: f32,
+    x_axis_color: vec4<f32>,
+    y_axis_color: vec4<f32>,
+    negative_axis_alpha: f32,
+    fade_start: f32,
+    fade_curve: u32,
+};
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0)
+var<uniform> material: LineMaterial;
+
+// Alpha multiplier of a `FadeCurve` at a distance, fading out from `start` to `end`
+fn fade_curve(dist: f32, start: f32, end: f32) -> f32 {
+    if material.fade_curve == 3u {
+        // Eased over the squared distance
+        return 1.0 - smoothstep(start * start, max(end * end, start * start + 0.0001), dist * dist);
+    }
+    let progress = clamp((dist - start) / max(end - start, 0.0001), 0.0, 1.0);
+    if material.fade_curve == 0u {
+        return 1.0 - progress;
+    } else if material.fade_curve == 2u {
+        return pow(1.0 - progress, 3.0);
+    }
+    return 1.0 - smoothstep(0.0, 1.0, progress);
+}
+
+@fragment
+fn fragment(
+    mesh: VertexOutput,
+) -> @location(0) vec4<f32> {
+    let dist = view.world_position.xy - mesh.world_position.xy;
+    let dist_squared = dot(dist, dist);
+    let radius_squared = material.radius * material.radius;
+    // Discard pixels after round border
+    if dist_squared > radius_squared {
+        discard;
+    }
+
+    // Color axis
+    let xmix = smoothstep(LOW, HIGH, abs(mesh.world_position.y));
+    let ymix = smoothstep(LOW, HIGH, abs(mesh.world_position.x));
+
+    var color = mix(material.x_axis_color, mix(material.y_axis_color, material.color, ymix), xmix);
+
+    // Dim the negative half of each axis
+    let negative = select(vec2(1.0), vec2(material.negative_axis_alpha), mesh.world_position.xy < vec2(0.0));
+    color.a *= mix(negative.x, mix(negative.y, 1.0, ymix), xmix);
+
+    // Only fade beyond the visible area, which follows the projection's scale
+    let extents = (vec2(1.0) + abs(view.clip_from_view[3].xy)) / vec2(view.clip_from_view[0].x, view.clip_from_view[1].y);
+    let fade_start = min(max(length(extents), material.fade_start), material.radius * MAX_FADE_START);
+    // Attenuate based on distance to camera for smooth borders
+    color.a *= fade_curve(sqrt(dist_squared), fade_start, material.radius);
+
+    return color;
+}
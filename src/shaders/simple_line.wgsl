@@ -1,15 +1,241 @@
-#import bevy_pbr::forward_io::VertexOutput
+#import bevy_pbr::fog::{atmospheric_fog, exponential_fog, exponential_squared_fog, linear_fog}
+#import bevy_pbr::mesh_functions::{get_world_from_local, mesh_position_local_to_world}
+#import bevy_pbr::mesh_view_bindings::{fog, lights, view}
+#import bevy_pbr::mesh_view_types::{FOG_MODE_ATMOSPHERIC, FOG_MODE_EXPONENTIAL, FOG_MODE_EXPONENTIAL_SQUARED, FOG_MODE_LINEAR}
+#import bevy_pbr::prepass_utils::prepass_depth
+#import bevy_pbr::view_transformations::{depth_ndc_to_view_z, position_world_to_clip}
+#ifdef TONEMAP_IN_SHADER
+#import bevy_core_pipeline::tonemapping::tone_mapping
+#endif
+
+// Clip-space depth under which the start of a line is behind the view
+const NEAR_W: f32 = 0.01;
+
+struct Vertex {
+    @builtin(instance_index) instance_index: u32,
+    @location(0) position: vec3<f32>,
+#ifdef LINE_DISTANCE
+    @location(1) line_distance: f32,
+#endif
+#ifdef LINE_EXPANSION
+    @location(2) other_end: vec3<f32>,
+    @location(3) expansion: vec2<f32>,
+#endif
+};
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) world_position: vec4<f32>,
+    @location(1) line_distance: f32,
+    // Clip position of the line's first vertex
+    @location(2) @interpolate(flat) line_start: vec4<f32>,
+    // Distance in pixels from the center of a line drawn as a quad
+    @location(3) line_offset: f32,
+};
+
+@vertex
+fn vertex(vertex: Vertex) -> VertexOutput {
+    var out: VertexOutput;
+    out.world_position = mesh_position_local_to_world(get_world_from_local(vertex.instance_index), vec4(vertex.position, 1.0));
+    out.position = position_world_to_clip(out.world_position.xyz);
+#ifdef LINE_DISTANCE
+    out.line_distance = vertex.line_distance;
+#endif
+    out.line_start = out.position;
+#ifdef LINE_EXPANSION
+    expand_line(vertex, &out);
+#endif
+    return out;
+}
+
+#ifdef LINE_EXPANSION
+// Expands a vertex of a line drawn as a quad across the line on screen, by half the line's width and a pixel for its anti-aliased edge
+fn expand_line(vertex: Vertex, out: ptr<function, VertexOutput>) {
+    let other_world = mesh_position_local_to_world(get_world_from_local(vertex.instance_index), vec4(vertex.other_end, 1.0));
+    var other = position_world_to_clip(other_world.xyz);
+    var clip = (*out).position;
+    if clip.w < NEAR_W && other.w < NEAR_W {
+        // Lines entirely behind the view collapse to a point outside of it
+        (*out).position = vec4(0.0, 0.0, -1.0, 1.0);
+        return;
+    }
+    // Ends behind the view are moved to where the line crosses the near plane, for the line's direction on screen to be defined
+    if clip.w < NEAR_W {
+        let t = (NEAR_W - clip.w) / (other.w - clip.w);
+        (*out).world_position = mix((*out).world_position, other_world, t);
+        (*out).line_distance += t * distance(vertex.position, vertex.other_end) * select(1.0, -1.0, vertex.expansion.y > 0.5);
+        clip = mix(clip, other, t);
+    } else if other.w < NEAR_W {
+        other = mix(clip, other, (NEAR_W - clip.w) / (other.w - clip.w));
+    }
+    (*out).line_start = select(clip, other, vertex.expansion.y > 0.5);
+
+    let half_viewport = view.viewport.zw * 0.5;
+    let screen_direction = other.xy / other.w * half_viewport - clip.xy / clip.w * half_viewport;
+    let direction = select(vec2(1.0, 0.0), normalize(screen_direction), dot(screen_direction, screen_direction) > 0.0);
+    let extent = material.line_width * 0.5 + 1.0;
+    let offset = vec2(-direction.y, direction.x) * vertex.expansion.x * extent;
+    (*out).position = vec4(clip.xy + offset / half_viewport * clip.w, clip.zw);
+    (*out).line_offset = vertex.expansion.x * extent;
+}
+#endif
 
 struct SimpleLineMaterial {
     color: vec4<f32>,
+    fade_start: f32,
+    fade_end: f32,
+    fade_curve: u32,
+    fade_center: vec3<f32>,
+    fade_from_center: u32,
+    soft_intersection: f32,
+    x_ray: u32,
+    x_ray_alpha: f32,
+    x_ray_dash: f32,
+    dash: f32,
+    gap: f32,
+    line_units: u32,
+    line_width: f32,
+    intensity: f32,
+    fog: u32,
+    fog_scattering: u32,
+    exposure: f32,
+    tonemapping: u32,
 };
 
 @group(#{MATERIAL_BIND_GROUP}) @binding(0)
 var<uniform> material: SimpleLineMaterial;
 
+// Alpha multiplier of a `FadeCurve` at a distance, fading out from `start` to `end`
+fn fade_curve(dist: f32, start: f32, end: f32) -> f32 {
+    if material.fade_curve == 3u {
+        // Eased over the squared distance
+        return 1.0 - smoothstep(start * start, max(end * end, start * start + 0.0001), dist * dist);
+    }
+    let progress = clamp((dist - start) / max(end - start, 0.0001), 0.0, 1.0);
+    if material.fade_curve == 0u {
+        return 1.0 - progress;
+    } else if material.fade_curve == 2u {
+        return pow(1.0 - progress, 3.0);
+    }
+    return 1.0 - smoothstep(0.0, 1.0, progress);
+}
+
+// Distance along the line in pixels, from where the line starts on screen,
+// or from where it crosses the near plane when it starts behind the view
+fn screen_line_distance(mesh: VertexOutput) -> f32 {
+    let clip = position_world_to_clip(mesh.world_position.xyz);
+    var start = mesh.line_start;
+    if start.w < NEAR_W {
+        start = mix(start, clip, (NEAR_W - start.w) / (clip.w - start.w));
+    }
+    let start_screen = (start.xy / start.w * vec2(0.5, -0.5) + 0.5) * view.viewport.zw + view.viewport.xy;
+    return distance(start_screen, mesh.position.xy);
+}
+
+// Whether a fragment lies in a gap of a `LineStyle`, given its distance along the line and the distance's change per pixel.
+// Dashes are at least a pixel long, for dots to be visible
+fn in_line_gap(dash: f32, gap: f32, dist: f32, dist_width: f32) -> bool {
+    if gap <= 0.0 {
+        return false;
+    }
+    let period = dash + gap;
+    return dist - floor(dist / period) * period >= max(dash, dist_width);
+}
+
+// Coverage of a fragment by a line drawn as a quad, given the fragment's distance from the line's center and the distance's change per pixel
+fn line_coverage(offset: f32, offset_width: f32) -> f32 {
+#ifdef LINE_EXPANSION
+    return clamp((material.line_width * 0.5 - abs(offset)) / max(offset_width, 0.0001) + 0.5, 0.0, 1.0);
+#else
+    return 1.0;
+#endif
+}
+
+// Alpha multiplier of a line's fragment, given the depth of the geometry behind it in the depth prepass
+fn occlusion_alpha(mesh: VertexOutput) -> f32 {
+#ifdef DEPTH_PREPASS
+    // Distance from the line to the geometry behind it, negative when the line is hidden
+    let gap = depth_ndc_to_view_z(mesh.position.z) - depth_ndc_to_view_z(prepass_depth(mesh.position, 0u));
+    if material.x_ray != 0u && gap < 0.0 {
+        // Hidden lines are dashed along the world's axis
+        let along = dot(mesh.world_position.xyz, vec3(1.0)) / (2.0 * material.x_ray_dash);
+        if fract(along) > 0.5 {
+            discard;
+        }
+        return material.x_ray_alpha;
+    }
+    if material.soft_intersection > 0.0 {
+        return clamp(gap / material.soft_intersection, 0.0, 1.0);
+    }
+#endif
+    return 1.0;
+}
+
+// Applies the view's fog, exposure and tonemapping to a color, following the grid's `GridShading`
+fn shade(input_color: vec4<f32>, world_position: vec3<f32>) -> vec4<f32> {
+    var color = vec4(input_color.rgb * mix(1.0, view.exposure, material.exposure), input_color.a);
+#ifdef DISTANCE_FOG
+    if material.fog != 0u {
+        color = apply_grid_fog(color, world_position);
+    }
+#endif
+#ifdef TONEMAP_IN_SHADER
+    if material.tonemapping != 0u {
+        color = tone_mapping(color, view.color_grading);
+    }
+#endif
+    return color;
+}
+
+// Applies the view's `DistanceFog` like lit materials do, without the shadows of the fog's inscattering
+fn apply_grid_fog(color: vec4<f32>, world_position: vec3<f32>) -> vec4<f32> {
+    let view_to_world = world_position - view.world_position.xyz;
+    let distance = length(view_to_world);
+    var scattering = vec3(0.0);
+    if material.fog_scattering != 0u && fog.directional_light_color.a > 0.0 {
+        let direction = view_to_world / distance;
+        for (var i = 0u; i < lights.n_directional_lights; i += 1u) {
+            let light = lights.directional_lights[i];
+            scattering += pow(max(dot(direction, light.direction_to_light), 0.0), fog.directional_light_exponent) * light.color.rgb * view.exposure;
+        }
+    }
+    if fog.mode == FOG_MODE_LINEAR {
+        return linear_fog(fog, color, distance, scattering);
+    } else if fog.mode == FOG_MODE_EXPONENTIAL {
+        return exponential_fog(fog, color, distance, scattering);
+    } else if fog.mode == FOG_MODE_EXPONENTIAL_SQUARED {
+        return exponential_squared_fog(fog, color, distance, scattering);
+    } else if fog.mode == FOG_MODE_ATMOSPHERIC {
+        return atmospheric_fog(fog, color, distance, scattering);
+    }
+    return color;
+}
+
 @fragment
 fn fragment(
     mesh: VertexOutput,
 ) -> @location(0) vec4<f32> {
-    return material.color;
+    // Follow the line's style, measuring distances before any discard for their derivatives
+    let line_dist = select(mesh.line_distance, screen_line_distance(mesh), material.line_units != 0u);
+    let offset_width = length(vec2(dpdx(mesh.line_offset), dpdy(mesh.line_offset)));
+    if in_line_gap(material.dash, material.gap, line_dist, fwidth(line_dist)) {
+        discard;
+    }
+
+    var color = material.color;
+
+    // Fade with the distance to the camera or to the fade's center, when enabled
+    if material.fade_end >= 0.0 {
+        let center = select(view.world_position.xyz, material.fade_center, material.fade_from_center != 0u);
+        let dist = distance(center, mesh.world_position.xyz);
+        if dist > material.fade_end {
+            discard;
+        }
+        color.a *= fade_curve(dist, material.fade_start, material.fade_end);
+    }
+    color.a *= occlusion_alpha(mesh) * line_coverage(mesh.line_offset, offset_width);
+    // Scale the emissive intensity, exceeding 1.0 in HDR
+    color = vec4(color.rgb * material.intensity, color.a);
+
+    return shade(color, mesh.world_position.xyz);
 }
@@ -0,0 +1,48 @@
+#import bevy_sprite::mesh2d_vertex_output::VertexOutput
+#import bevy_sprite::mesh2d_view_bindings::view
+
+struct SimpleLineMaterial {
+    color: vec4<f32>,
+    fade_start: f32,
+    fade_end: f32,
+    fade_curve: u32,
+    fade_center: vec2<f32>,
+    fade_from_center: u32,
+};
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0)
+var<uniform> material: SimpleLineMaterial;
+
+// Alpha multiplier of a `FadeCurve` at a distance, fading out from `start` to `end`
+fn fade_curve(dist: f32, start: f32, end: f32) -> f32 {
+    if material.fade_curve == 3u {
+        // Eased over the squared distance
+        return 1.0 - smoothstep(start * start, max(end * end, start * start + 0.0001), dist * dist);
+    }
+    let progress = clamp((dist - start) / max(end - start, 0.0001), 0.0, 1.0);
+    if material.fade_curve == 0u {
+        return 1.0 - progress;
+    } else if material.fade_curve == 2u {
+        return pow(1.0 - progress, 3.0);
+    }
+    return 1.0 - smoothstep(0.0, 1.0, progress);
+}
+
+@fragment
+fn fragment(
+    mesh: VertexOutput,
+) -> @location(0) vec4<f32> {
+    var color = material.color;
+
+    // Fade with the distance to the camera or to the fade's center on the XY plane, when enabled
+    if material.fade_end >= 0.0 {
+        let center = select(view.world_position.xy, material.fade_center, material.fade_from_center != 0u);
+        let dist = distance(center, mesh.world_position.xy);
+        if dist > material.fade_end {
+            discard;
+        }
+        color.a *= fade_curve(dist, material.fade_start, material.fade_end);
+    }
+
+    return color;
+}
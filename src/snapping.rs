@@ -0,0 +1,130 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::*;
+
+/// System parameter snapping world positions to the lines of grid entities.
+/// It resolves a grid's `GlobalTransform`, its plane (following the `TrackedGrid` alignment and offset) and its `SubGrid`,
+/// so that snapping is done in world space.
+///
+/// Every method returns `None` when the entity is not a grid.
+#[derive(SystemParam)]
+pub struct GridQuery<'w, 's> {
+    query_grid: Query<
+        'w,
+        's,
+        (
+            &'static Grid,
+            &'static GlobalTransform,
+            Option<&'static TrackedGrid>,
+            Option<&'static SubGrid>,
+        ),
+    >,
+    #[cfg(feature = "2d")]
+    query_2d: Query<'w, 's, (), With<Grid2d>>,
+}
+
+impl GridQuery<'_, '_> {
+    /// Returns the alignment of a grid's plane, see `grid_plane_alignment`
+    pub fn alignment(&self, entity: Entity) -> Option<GridAlignment> {
+        let (_, _, tracked, _) = self.query_grid.get(entity).ok()?;
+        #[cfg(feature = "2d")]
+        let is_2d = self.query_2d.contains(entity);
+        #[cfg(not(feature = "2d"))]
+        let is_2d = false;
+        Some(grid_plane_alignment(tracked, is_2d))
+    }
+
+    /// Returns the index of a grid's cell containing a world position, see `Grid::cell`.
+    /// Cells are indexed in world space for tracked grids, and in the grid's local space otherwise.
+    pub fn cell(&self, entity: Entity, world_position: Vec3) -> Option<IVec2> {
+        let alignment = self.alignment(entity)?;
+        let (grid, transform, tracked, _) = self.query_grid.get(entity).ok()?;
+        let local_position = transform
+            .affine()
+            .inverse()
+            .transform_point3(world_position);
+        Some(
+            grid.cell(&alignment, local_position)
+                + tracked_cell_offset(grid, tracked, &alignment, transform.translation()),
+        )
+    }
+
+    /// Returns the offset between two world positions along the two axis of a grid's plane
+    /// (see `GridAlignment::plane_axis`), in cells of the grid
+    pub fn cell_delta(&self, entity: Entity, from: Vec3, to: Vec3) -> Option<Vec2> {
+        let alignment = self.alignment(entity)?;
+        let (grid, transform, _, _) = self.query_grid.get(entity).ok()?;
+        let inverse = transform.affine().inverse();
+        let delta = inverse.transform_point3(to) - inverse.transform_point3(from);
+        let [first, second] = alignment.plane_axis();
+        Some(
+            Vec2::new(
+                delta.dot(first.to_axis_vec3()),
+                delta.dot(second.to_axis_vec3()),
+            ) / grid.spacing,
+        )
+    }
+
+    /// Runs a snapping function within a grid's local space, and returns its result in world space
+    fn snap_with(
+        &self,
+        entity: Entity,
+        world_position: Vec3,
+        snap: impl FnOnce(&Grid, Option<&SubGrid>, &GridAlignment, Vec3) -> Vec3,
+    ) -> Option<Vec3> {
+        let alignment = self.alignment(entity)?;
+        let (grid, transform, _, sub_grid) = self.query_grid.get(entity).ok()?;
+        let local_position = transform
+            .affine()
+            .inverse()
+            .transform_point3(world_position);
+        Some(transform.transform_point(snap(grid, sub_grid, &alignment, local_position)))
+    }
+
+    /// Snaps a world position to a grid's nearest line intersection within planes parallel to the grid,
+    /// see `Grid::snap`
+    pub fn snap(&self, entity: Entity, world_position: Vec3) -> Option<Vec3> {
+        self.snap_with(entity, world_position, |grid, _, alignment, position| {
+            grid.snap(alignment, position)
+        })
+    }
+
+    /// Snaps a world position to the nearest line intersection of a grid or its `SubGrid`,
+    /// see `Grid::snap_to_sub_grid`.
+    /// Falls back to the main grid when the grid has no `SubGrid`.
+    pub fn snap_to_sub_grid(&self, entity: Entity, world_position: Vec3) -> Option<Vec3> {
+        self.snap_with(
+            entity,
+            world_position,
+            |grid, sub_grid, alignment, position| match sub_grid {
+                Some(sub_grid) => grid.snap_to_sub_grid(sub_grid, alignment, position),
+                None => grid.snap(alignment, position),
+            },
+        )
+    }
+
+    /// Returns a grid's line intersection nearest to a world position, on the grid's plane,
+    /// see `Grid::nearest_intersection`
+    pub fn nearest_intersection(&self, entity: Entity, world_position: Vec3) -> Option<Vec3> {
+        self.snap_with(entity, world_position, |grid, _, alignment, position| {
+            grid.nearest_intersection(alignment, position)
+        })
+    }
+
+    /// Returns the point on a grid's nearest line to a world position, along with the local axis the line is parallel to,
+    /// see `Grid::nearest_line`
+    pub fn nearest_line(
+        &self,
+        entity: Entity,
+        world_position: Vec3,
+    ) -> Option<(GridAlignment, Vec3)> {
+        let mut line_axis = None;
+        let point = self.snap_with(entity, world_position, |grid, _, alignment, position| {
+            let (axis, point) = grid.nearest_line(alignment, position);
+            line_axis = Some(axis);
+            point
+        })?;
+        Some((line_axis?, point))
+    }
+}
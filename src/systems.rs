@@ -1,10 +1,16 @@
 #![allow(clippy::type_complexity)]
 
+#[cfg(feature = "3d")]
 use bevy::asset::RenderAssetUsages;
+#[cfg(feature = "3d")]
 use bevy::camera::visibility::RenderLayers;
+#[cfg(feature = "3d")]
 use bevy::light::NotShadowCaster;
+#[cfg(feature = "3d")]
+use bevy::mesh::Indices;
 use bevy::platform::collections::HashMap;
 use bevy::prelude::*;
+#[cfg(feature = "3d")]
 use bevy::render::render_resource::PrimitiveTopology;
 
 use crate::*;
@@ -13,11 +19,49 @@ use crate::*;
 /// Purely used to avoid Z-fighting.
 /// The mesh is offset by it, and the mesh's transform is offset by it as well.
 /// Can be any reasonable float value.
-const SUB_GRID_VERTICAL_OFFSET: f32 = -0.001_f32;
+#[cfg(any(feature = "3d", feature = "2d", feature = "gizmos"))]
+pub(crate) const SUB_GRID_VERTICAL_OFFSET: f32 = -0.001_f32;
+
+/// Sorting bias of the main grid's lines, drawing them over the sub-grid's when blended
+#[cfg(feature = "3d")]
+pub(crate) const GRID_DEPTH_BIAS: f32 = 0.01_f32;
+
+/// Sorting bias of the axis lines, drawing them over the main grid's when blended
+#[cfg(feature = "3d")]
+pub(crate) const AXIS_DEPTH_BIAS: f32 = 0.02_f32;
+
+/// Offset on the vertical axis for the fill of a grid, below its sub-grid
+#[cfg(feature = "3d")]
+const GRID_FILL_VERTICAL_OFFSET: f32 = 2.0_f32 * SUB_GRID_VERTICAL_OFFSET;
+
+/// Sorting bias of the fill of a grid, drawing it under the lines when blended
+#[cfg(feature = "3d")]
+const GRID_FILL_DEPTH_BIAS: f32 = -0.01_f32;
+
+/// Filter for grids meshed by the 3D systems, excluding grids rendered by other backends
+#[cfg(feature = "3d")]
+type Mesh3dBackend = (Grid2dBackend, GizmoBackend);
+#[cfg(all(feature = "3d", feature = "2d"))]
+type Grid2dBackend = Without<Grid2d>;
+#[cfg(all(feature = "3d", not(feature = "2d")))]
+type Grid2dBackend = ();
+
+/// Filter excluding grids drawn with gizmos from meshing
+#[cfg(all(any(feature = "3d", feature = "2d"), feature = "gizmos"))]
+pub(crate) type GizmoBackend = Without<GizmoGrid>;
+#[cfg(all(any(feature = "3d", feature = "2d"), not(feature = "gizmos")))]
+pub(crate) type GizmoBackend = ();
+
+/// Filter excluding the overlay cameras of `OrientationGizmo`s from the entities grids and tools are tracked to
+#[cfg(feature = "orientation")]
+pub(crate) type TrackedEntityFilter = Without<OrientationGizmoOverlay>;
+#[cfg(not(feature = "orientation"))]
+pub(crate) type TrackedEntityFilter = ();
 
 /// Utility function to despawn children of a certain type.
 /// Used with marker components.
-fn despawn_children_of_type<T: Component>(
+#[cfg(any(feature = "3d", feature = "2d", feature = "labels"))]
+pub(crate) fn despawn_children_of_type<T: Component>(
     commands: &mut Commands,
     parent: Entity,
     children: &Children,
@@ -34,6 +78,7 @@ fn despawn_children_of_type<T: Component>(
 }
 
 /// Creates vertices for a line based on the line's size and its offset
+#[cfg(any(feature = "3d", feature = "2d", feature = "gizmos"))]
 fn line_vertices(size: f32, horizontal_offset: f32, vertical_offset: f32) -> [Vec3; 8] {
     [
         // +X line
@@ -51,39 +96,246 @@ fn line_vertices(size: f32, horizontal_offset: f32, vertical_offset: f32) -> [Ve
     ]
 }
 
+/// Creates vertices for the markers of a `GridStyle` at every intersection of lines at `positions`, given their offset.
+/// Positions are paired with whether they belong to the main grid, `skip_main` skipping intersections of two main grid lines.
+#[cfg(any(feature = "3d", feature = "2d", feature = "gizmos"))]
+fn intersection_marker_vertices(
+    style: GridStyle,
+    positions: &[(f32, bool)],
+    vertical_offset: f32,
+    skip_main: bool,
+) -> Vec<Vec3> {
+    positions
+        .iter()
+        .flat_map(|first| positions.iter().map(move |second| (first, second)))
+        .filter(|((_, first_main), (_, second_main))| !(skip_main && *first_main && *second_main))
+        .flat_map(|((x, _), (z, _))| style.marker_vertices(Vec3::new(*x, vertical_offset, *z)))
+        .collect()
+}
+
+/// Returns the positions of the main grid's lines along one axis, including its center line
+#[cfg(any(feature = "3d", feature = "2d", feature = "gizmos"))]
+fn main_line_positions(grid: &Grid) -> impl Iterator<Item = f32> + '_ {
+    let count = grid.count as i32;
+    (-count..=count).map(|offset| offset as f32 * grid.spacing)
+}
+
 /// Returns the a mesh of vertices for a main grid, along with the grid's size
-fn main_grid_vertices_and_size(grid: &Grid, alignment: &GridAlignment) -> (Vec<Vec3>, f32) {
+#[cfg(any(feature = "3d", feature = "2d", feature = "gizmos"))]
+pub(crate) fn main_grid_vertices_and_size(
+    grid: &Grid,
+    alignment: &GridAlignment,
+) -> (Vec<Vec3>, f32) {
     let size = grid.count as f32 * grid.spacing;
-    let vertices = (0..grid.count)
-        .map(|offset| (offset + 1) as f32 * grid.spacing)
-        .flat_map(|offset| line_vertices(size, offset, 0.0_f32))
+    let vertices = if grid.style.is_lines() {
+        (0..grid.count)
+            .map(|offset| (offset + 1) as f32 * grid.spacing)
+            .flat_map(|offset| line_vertices(size, offset, 0.0_f32))
+            .collect::<Vec<_>>()
+    } else {
+        let positions = main_line_positions(grid)
+            .map(|position| (position, true))
+            .collect::<Vec<_>>();
+        intersection_marker_vertices(grid.style, &positions, 0.0_f32, false)
+    };
+    let vertices = vertices
+        .into_iter()
         .map(|vertex| alignment.shift_vec3(vertex))
         .collect::<Vec<_>>();
     (vertices, size)
 }
 
+/// Returns the a mesh of vertices for a sub-grid
+#[cfg(any(feature = "3d", feature = "2d", feature = "gizmos"))]
+pub(crate) fn sub_grid_vertices(
+    grid: &Grid,
+    sub_grid: &SubGrid,
+    alignment: &GridAlignment,
+) -> Vec<Vec3> {
+    let size = grid.count as f32 * grid.spacing;
+    let sub_spacing = sub_grid.spacing(grid);
+    let offsets = (0..grid.count)
+        .flat_map(|offset| (0..sub_grid.count).map(move |sub_offset| (offset, sub_offset)))
+        .map(|(offset, sub_offset)| {
+            (sub_offset as f32).mul_add(sub_spacing, offset as f32 * grid.spacing + sub_spacing)
+        });
+    let vertices = if grid.style.is_lines() {
+        offsets
+            .flat_map(|offset| line_vertices(size, offset, SUB_GRID_VERTICAL_OFFSET))
+            .collect::<Vec<_>>()
+    } else {
+        // Sub-grid markers lie where sub-grid lines cross any line, main grid intersections being left to the main grid
+        let positions = offsets
+            .flat_map(|offset| [(offset, false), (-offset, false)])
+            .chain(main_line_positions(grid).map(|position| (position, true)))
+            .collect::<Vec<_>>();
+        intersection_marker_vertices(grid.style, &positions, SUB_GRID_VERTICAL_OFFSET, true)
+    };
+    vertices
+        .into_iter()
+        .map(|vertex| alignment.shift_vec3(vertex))
+        .collect::<Vec<_>>()
+}
+
+/// Returns the decimal count needed to display coordinates spaced by `spacing`, up to 4
+#[cfg(any(feature = "labels", feature = "rulers", feature = "measure"))]
+pub(crate) fn coordinate_precision(spacing: f32) -> usize {
+    (0..4)
+        .find(|decimals| {
+            let scaled = spacing * 10.0_f32.powi(*decimals as i32);
+            (scaled - scaled.round()).abs() < 0.001_f32
+        })
+        .unwrap_or(4)
+}
+
+/// Returns the alpha multiplier of a tracked grid's point, given its squared distance to the tracked entity within the grid's plane.
+/// Matches the distance fade of the `ClippedLineMaterial`, following the grid's `GridFade` with `radius` being its default end.
+#[cfg(any(feature = "gizmos", feature = "labels"))]
+pub(crate) fn distance_fade(dist_squared: f32, radius: f32, fade: Option<&GridFade>) -> f32 {
+    fade.copied()
+        .unwrap_or_default()
+        .alpha(dist_squared.sqrt(), radius)
+}
+
+/// Returns the alignment of a grid's plane.
+/// Tracked grids lie on their tracking axis, untracked 2D grids on the XY plane, and other untracked grids are floors.
+pub fn grid_plane_alignment(tracked: Option<&TrackedGrid>, is_2d: bool) -> GridAlignment {
+    match tracked {
+        Some(tracked) => tracked.alignment,
+        None if is_2d => GridAlignment::Z,
+        None => GridAlignment::default(),
+    }
+}
+
+/// Returns the offset, in cells, between a grid's local cells and the cells it reports.
+/// Tracked grids snap by whole cells, and thus report cells in world space.
+pub(crate) fn tracked_cell_offset(
+    grid: &Grid,
+    tracked: Option<&TrackedGrid>,
+    alignment: &GridAlignment,
+    translation: Vec3,
+) -> IVec2 {
+    if tracked.is_none() {
+        return IVec2::ZERO;
+    }
+    let [first, second] = alignment.plane_axis();
+    IVec2::new(
+        (translation.dot(first.to_axis_vec3()) / grid.spacing).round() as i32,
+        (translation.dot(second.to_axis_vec3()) / grid.spacing).round() as i32,
+    )
+}
+
+/// Intersects a ray with the plane of a grid, within the grid's extent.
+/// Returns the hit in the grid's local space and in world space, along with its signed distance along the ray.
+/// Planes behind the ray's origin are only hit by `orthographic` rays, as an orthographic projection sees them.
+#[cfg(any(feature = "hover", feature = "picking"))]
+pub(crate) fn ray_grid_intersection(
+    ray: Ray3d,
+    grid: &Grid,
+    alignment: &GridAlignment,
+    transform: &GlobalTransform,
+    orthographic: bool,
+) -> Option<(Vec3, Vec3, f32)> {
+    let inverse = transform.affine().inverse();
+    let origin = inverse.transform_point3(ray.origin);
+    let direction = inverse.transform_vector3(*ray.direction);
+    let normal = alignment.to_axis_vec3();
+    let facing = direction.dot(normal);
+    if facing.abs() < f32::EPSILON {
+        return None;
+    }
+    let distance = -origin.dot(normal) / facing;
+    if distance <= 0.0_f32 && !orthographic {
+        return None;
+    }
+    let local = origin + direction * distance;
+    let size = grid.count as f32 * grid.spacing;
+    if (local * alignment.to_inverted_axis_vec3())
+        .abs()
+        .max_element()
+        > size
+    {
+        return None;
+    }
+    let world = transform.transform_point(local);
+    Some((local, world, (world - ray.origin).dot(*ray.direction)))
+}
+
+/// Returns a copy of a grid with its line count replaced by the `TrackedGridCount`, if any
+#[cfg(any(
+    feature = "3d",
+    feature = "2d",
+    feature = "gizmos",
+    feature = "labels",
+    feature = "hover",
+    feature = "picking"
+))]
+pub(crate) fn tracked_grid_with_count(grid: &Grid, count: Option<&TrackedGridCount>) -> Grid {
+    Grid {
+        count: count.map_or(grid.count, |count| count.0),
+        ..grid.clone()
+    }
+}
+
+#[cfg(any(feature = "3d", feature = "gizmos"))]
+/// Returns a color replaced by the `GridHeightColor` at a tracked grid's offset, or unchanged for untracked grids
+pub(crate) fn height_tinted(
+    color: Color,
+    tracked: Option<&TrackedGrid>,
+    height_color: Option<&GridHeightColor>,
+) -> Color {
+    match (tracked, height_color) {
+        (Some(tracked), Some(height_color)) => height_color.tint(color, tracked.offset),
+        _ => color,
+    }
+}
+
+#[cfg(feature = "3d")]
 /// System for meshing untracked (`Without<TrackedGrid>`) grids
 pub fn main_grid_mesher_untracked(
     mut commands: Commands,
     query_parent: Query<
-        (Entity, &Grid, Option<&RenderLayers>, Option<&Children>),
         (
-            Or<(Changed<Grid>, Changed<RenderLayers>)>,
+            Entity,
+            &Grid,
+            Option<&GridFade>,
+            Option<&GridOcclusion>,
+            Option<&GridAntialiasing>,
+            Option<&GridShading>,
+            Option<&GridFill>,
+            Option<&RenderLayers>,
+            Option<&Children>,
+        ),
+        (
+            Or<(
+                Changed<Grid>,
+                Changed<GridFade>,
+                Changed<GridOcclusion>,
+                Changed<GridAntialiasing>,
+                Changed<GridShading>,
+                Changed<GridFill>,
+                Changed<RenderLayers>,
+            )>,
             Without<TrackedGrid>,
+            Mesh3dBackend,
         ),
     >,
     query_children: Query<Entity, With<GridChild>>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut simple_materials: ResMut<Assets<SimpleLineMaterial>>,
 ) {
-    for (entity, grid, render_layers, children) in query_parent.iter() {
-        let (vertices, _) = main_grid_vertices_and_size(grid, &GridAlignment::default());
-        let mut mesh = Mesh::new(PrimitiveTopology::LineList, RenderAssetUsages::all());
-        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertices);
-
+    for (entity, grid, fade, occlusion, antialiasing, shading, fill, render_layers, children) in
+        query_parent.iter()
+    {
         if let Some(children) = children {
             despawn_children_of_type(&mut commands, entity, children, &query_children);
         }
+        if !fill.is_none_or(GridFill::draws_lines) {
+            continue;
+        }
+
+        let (vertices, size) = main_grid_vertices_and_size(grid, &GridAlignment::default());
+        let mesh = marker_mesh(vertices, grid.style, antialiasing);
         commands.entity(entity).with_children(|children| {
             let mut commands = children.spawn((
                 GridChild,
@@ -92,8 +344,16 @@ pub fn main_grid_mesher_untracked(
                 Transform::default(),
                 Visibility::default(),
                 MeshMaterial3d(
-                    simple_materials
-                        .add(SimpleLineMaterial::from_color(grid.color, grid.alpha_mode)),
+                    simple_materials.add(
+                        SimpleLineMaterial::from_color(grid.color, grid.alpha_mode)
+                            .with_fade(fade, size - grid.spacing)
+                            .with_occlusion(occlusion)
+                            .with_antialiasing(antialiasing)
+                            .with_shading(shading)
+                            .with_intensity(grid.intensity)
+                            .with_depth_bias(GRID_DEPTH_BIAS)
+                            .with_line_style(grid.line_style),
+                    ),
                 ),
             ));
             if let Some(render_layers) = render_layers {
@@ -103,6 +363,39 @@ pub fn main_grid_mesher_untracked(
     }
 }
 
+/// Returns the line lists of a tracked grid's markers staying at origin, along with their colors:
+/// the arrowheads of the axis within the grid's plane, and the origin marker.
+/// The axis themselves are part of the grid's mesh.
+///
+/// Tracked grids are clipped at `radius` around the camera, arrowheads are thus placed where the grid starts fading,
+/// to be seen from around the origin.
+#[cfg(any(feature = "3d", feature = "2d", feature = "gizmos"))]
+pub(crate) fn tracked_axis_markers(
+    axis: &GridAxis,
+    radius: f32,
+    spacing: f32,
+    alignment: GridAlignment,
+    fade: Option<&GridFade>,
+) -> Vec<(Vec<Vec3>, Color)> {
+    let mut lines = Vec::new();
+    if axis.arrowheads {
+        let (fade_start, _) = fade.copied().unwrap_or_default().distances(radius);
+        for in_plane in alignment.plane_axis() {
+            if let Some(color) = axis.get_by_alignment(&in_plane) {
+                lines.push((
+                    GridAxis::create_arrowhead(fade_start, spacing, in_plane),
+                    color,
+                ));
+            }
+        }
+    }
+    if let Some(color) = axis.origin {
+        lines.push((GridAxis::create_origin_marker(spacing), color));
+    }
+    lines
+}
+
+#[cfg(feature = "3d")]
 /// System for meshing tracked (`With<TrackedGrid>`) grids
 pub fn main_grid_mesher_tracked(
     mut commands: Commands,
@@ -111,86 +404,177 @@ pub fn main_grid_mesher_tracked(
             Entity,
             &Grid,
             &TrackedGrid,
+            Option<&TrackedGridCount>,
             Option<&GridAxis>,
+            Option<&GridFade>,
+            Option<&GridOcclusion>,
+            Option<&GridAntialiasing>,
+            Option<&GridShading>,
+            Option<&GridFill>,
+            Option<&GridHeightColor>,
             Option<&RenderLayers>,
             Option<&Children>,
         ),
-        Or<(
-            Changed<Grid>,
-            Changed<TrackedGrid>,
-            Changed<GridAxis>,
-            Changed<RenderLayers>,
-        )>,
+        (
+            Or<(
+                Changed<Grid>,
+                Changed<TrackedGrid>,
+                Changed<TrackedGridCount>,
+                Changed<GridAxis>,
+                Changed<GridFade>,
+                Changed<GridOcclusion>,
+                Changed<GridAntialiasing>,
+                Changed<GridShading>,
+                Changed<GridFill>,
+                Changed<GridHeightColor>,
+                Changed<RenderLayers>,
+            )>,
+            Mesh3dBackend,
+        ),
     >,
     query_children: Query<Entity, With<GridChild>>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut clipped_materials: ResMut<Assets<ClippedLineMaterial>>,
 ) {
-    for (entity, grid, tracked, axis, render_layers, children) in query_parent.iter() {
+    for (
+        entity,
+        grid,
+        tracked,
+        count,
+        axis,
+        fade,
+        occlusion,
+        antialiasing,
+        shading,
+        fill,
+        height_color,
+        render_layers,
+        children,
+    ) in query_parent.iter()
+    {
+        let grid = &Grid {
+            color: height_tinted(grid.color, Some(tracked), height_color),
+            ..tracked_grid_with_count(grid, count)
+        };
         let (mut vertices, size) = main_grid_vertices_and_size(grid, &tracked.alignment);
-        for alignment in [GridAlignment::X, GridAlignment::Z] {
-            vertices.extend(
-                &GridAxis::create_single_axis(size, alignment)
-                    .map(|vertex| tracked.alignment.shift_vec3(vertex)),
-            );
+        if !fill.is_none_or(GridFill::draws_lines) {
+            vertices.clear();
+        }
+        // Center lines are kept for the axis colored by the `GridAxis` when only markers or the fill are drawn
+        let draws_center_lines = grid.style.is_lines() && fill.is_none_or(GridFill::draws_lines);
+        let mut center_lines = Vec::new();
+        for in_plane in tracked.alignment.plane_axis() {
+            if draws_center_lines
+                || axis.is_some_and(|axis| axis.get_by_alignment(&in_plane).is_some())
+            {
+                center_lines.extend(GridAxis::create_single_axis(size, in_plane));
+            }
         }
-        let mut mesh = Mesh::new(PrimitiveTopology::LineList, RenderAssetUsages::all());
-        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertices);
+        // Filled markers are triangles, meshed apart from the center lines
+        let mut grid_meshes = if grid.style.is_filled() {
+            vec![
+                marker_mesh(vertices, grid.style, antialiasing),
+                line_mesh(center_lines, antialiasing),
+            ]
+        } else {
+            vertices.extend(center_lines);
+            vec![line_mesh(vertices, antialiasing)]
+        };
+        grid_meshes.retain(|mesh| mesh.count_vertices() > 0);
 
         if let Some(children) = children {
             despawn_children_of_type(&mut commands, entity, children, &query_children);
         }
         // Main grid lines move with the grid
-        commands.entity(entity).with_children(|children| {
-            let mut commands = children.spawn((
-                GridChild,
-                Mesh3d(meshes.add(mesh)),
-                NotShadowCaster,
-                Transform::default(),
-                Visibility::default(),
-                MeshMaterial3d(clipped_materials.add(ClippedLineMaterial::new(
+        if !grid_meshes.is_empty() {
+            let material = clipped_materials.add(
+                ClippedLineMaterial::new(
                     grid.color,
                     grid.alpha_mode,
                     tracked.alignment,
                     size - grid.spacing,
                     tracked.offset,
                     axis,
-                ))),
-            ));
-            if let Some(render_layers) = render_layers {
-                commands.insert(render_layers.clone());
-            }
-        });
-
-        // Tracked axis line stays at origin, but inherits visibility through a parent entity
-        if let Some(color) = axis.and_then(|axis| axis.get_by_alignment(&tracked.alignment)) {
-            let vertices = GridAxis::create_single_axis(size, tracked.alignment).to_vec();
-            let mut axis_mesh = Mesh::new(PrimitiveTopology::LineList, RenderAssetUsages::all());
-            axis_mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertices);
+                )
+                .with_fade(fade)
+                .with_occlusion(occlusion)
+                .with_antialiasing(antialiasing)
+                .with_shading(shading)
+                .with_intensity(grid.intensity)
+                .with_depth_bias(GRID_DEPTH_BIAS)
+                .with_line_style(grid.line_style),
+            );
+            commands.entity(entity).with_children(|children| {
+                for mesh in grid_meshes {
+                    let mut commands = children.spawn((
+                        GridChild,
+                        Mesh3d(meshes.add(mesh)),
+                        NotShadowCaster,
+                        Transform::default(),
+                        Visibility::default(),
+                        MeshMaterial3d(material.clone()),
+                    ));
+                    if let Some(render_layers) = render_layers {
+                        commands.insert(render_layers.clone());
+                    }
+                }
+            });
+        }
 
+        // Tracked axis line and markers stay at origin, but inherit visibility through a parent entity
+        let axis_lines = axis
+            .map(|axis| {
+                let mut lines = axis.create_axis_lines(size, grid.spacing, tracked.alignment);
+                lines.extend(tracked_axis_markers(
+                    axis,
+                    size - grid.spacing,
+                    grid.spacing,
+                    tracked.alignment,
+                    fade,
+                ));
+                lines
+            })
+            .unwrap_or_default();
+        if !axis_lines.is_empty() {
             // Create a parent entity that only handles visibility inheritance
             commands.entity(entity).with_children(|children| {
                 children
                     .spawn((GridChild, GlobalTransform::default(), Visibility::default()))
                     .with_children(|axis_parent| {
-                        let mut axis_commands = axis_parent.spawn((
-                            GridChild,
-                            Mesh3d(meshes.add(axis_mesh)),
-                            NotShadowCaster,
-                            Transform::default(),
-                            GlobalTransform::default(),
-                            Visibility::default(),
-                            MeshMaterial3d(clipped_materials.add(ClippedLineMaterial::new(
-                                color,
-                                grid.alpha_mode,
-                                tracked.alignment,
-                                size - grid.spacing,
-                                tracked.offset,
-                                None,
-                            ))),
-                        ));
-                        if let Some(render_layers) = render_layers {
-                            axis_commands.insert(render_layers.clone());
+                        for (vertices, color) in axis_lines {
+                            let axis_mesh = line_mesh(vertices, antialiasing);
+                            let mut axis_commands = axis_parent.spawn((
+                                GridChild,
+                                Mesh3d(meshes.add(axis_mesh)),
+                                NotShadowCaster,
+                                Transform::default(),
+                                GlobalTransform::default(),
+                                Visibility::default(),
+                                MeshMaterial3d(
+                                    clipped_materials.add(
+                                        ClippedLineMaterial::new(
+                                            color,
+                                            grid.alpha_mode,
+                                            tracked.alignment,
+                                            size - grid.spacing,
+                                            tracked.offset,
+                                            None,
+                                        )
+                                        .with_fade(fade)
+                                        .with_occlusion(occlusion)
+                                        .with_antialiasing(antialiasing)
+                                        .with_shading(shading)
+                                        .with_intensity(axis.map_or(1.0_f32, |axis| axis.intensity))
+                                        .with_depth_bias(AXIS_DEPTH_BIAS)
+                                        .with_line_style(
+                                            axis.map_or(LineStyle::Solid, |axis| axis.line_style),
+                                        ),
+                                    ),
+                                ),
+                            ));
+                            if let Some(render_layers) = render_layers {
+                                axis_commands.insert(render_layers.clone());
+                            }
                         }
                     });
             });
@@ -198,6 +582,7 @@ pub fn main_grid_mesher_tracked(
     }
 }
 
+#[cfg(feature = "3d")]
 /// System for meshing sub-grids
 pub fn sub_grid_mesher(
     mut commands: Commands,
@@ -207,42 +592,70 @@ pub fn sub_grid_mesher(
             &Grid,
             &SubGrid,
             Option<&TrackedGrid>,
+            Option<&TrackedGridCount>,
+            Option<&GridFade>,
+            Option<&GridOcclusion>,
+            Option<&GridAntialiasing>,
+            Option<&GridShading>,
+            Option<&GridFill>,
+            Option<&GridHeightColor>,
             Option<&RenderLayers>,
             Option<&Children>,
         ),
-        Or<(
-            Changed<Grid>,
-            Changed<SubGrid>,
-            Changed<TrackedGrid>,
-            Changed<RenderLayers>,
-        )>,
+        (
+            Or<(
+                Changed<Grid>,
+                Changed<SubGrid>,
+                Changed<TrackedGrid>,
+                Changed<TrackedGridCount>,
+                Changed<GridFade>,
+                Changed<GridOcclusion>,
+                Changed<GridAntialiasing>,
+                Changed<GridShading>,
+                Changed<GridFill>,
+                Changed<GridHeightColor>,
+                Changed<RenderLayers>,
+            )>,
+            Mesh3dBackend,
+        ),
     >,
     query_children: Query<Entity, With<SubGridChild>>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut clipped_materials: ResMut<Assets<ClippedLineMaterial>>,
     mut simple_materials: ResMut<Assets<SimpleLineMaterial>>,
 ) {
-    for (entity, grid, sub_grid, tracked, render_layers, children) in query_parent.iter() {
+    for (
+        entity,
+        grid,
+        sub_grid,
+        tracked,
+        count,
+        fade,
+        occlusion,
+        antialiasing,
+        shading,
+        fill,
+        height_color,
+        render_layers,
+        children,
+    ) in query_parent.iter()
+    {
+        let grid = &tracked_grid_with_count(grid, count);
         let size = grid.count as f32 * grid.spacing;
-        let sub_spacing = grid.spacing / (sub_grid.count + 1) as f32;
+        let sub_color = height_tinted(sub_grid.color, tracked, height_color);
 
         let alignment = tracked
             .map(|tracked| tracked.alignment)
             .unwrap_or_else(GridAlignment::default);
-        let vertices = (0..grid.count)
-            .flat_map(|offset| (0..sub_grid.count).map(move |sub_offset| (offset, sub_offset)))
-            .map(|(offset, sub_offset)| {
-                (sub_offset as f32).mul_add(sub_spacing, offset as f32 * grid.spacing + sub_spacing)
-            })
-            .flat_map(|offset| line_vertices(size, offset, SUB_GRID_VERTICAL_OFFSET))
-            .map(|vertex| alignment.shift_vec3(vertex))
-            .collect::<Vec<_>>();
-        let mut mesh = Mesh::new(PrimitiveTopology::LineList, RenderAssetUsages::all());
-        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertices);
-
         if let Some(children) = children {
             despawn_children_of_type(&mut commands, entity, children, &query_children);
         }
+        if !fill.is_none_or(GridFill::draws_lines) {
+            continue;
+        }
+
+        let vertices = sub_grid_vertices(grid, sub_grid, &alignment);
+        let mesh = marker_mesh(vertices, grid.style, antialiasing);
         commands.entity(entity).with_children(|children| {
             let mut child_commands = children.spawn((
                 SubGridChild,
@@ -254,20 +667,36 @@ pub fn sub_grid_mesher(
                 Visibility::default(),
             ));
             if let Some(tracked) = tracked {
-                child_commands.insert(MeshMaterial3d(clipped_materials.add(
-                    ClippedLineMaterial::new(
-                        sub_grid.color,
-                        grid.alpha_mode,
-                        tracked.alignment,
-                        size - grid.spacing,
-                        tracked.offset,
-                        None,
+                child_commands.insert(MeshMaterial3d(
+                    clipped_materials.add(
+                        ClippedLineMaterial::new(
+                            sub_color,
+                            grid.alpha_mode,
+                            tracked.alignment,
+                            size - grid.spacing,
+                            tracked.offset,
+                            None,
+                        )
+                        .with_fade(fade)
+                        .with_occlusion(occlusion)
+                        .with_antialiasing(antialiasing)
+                        .with_shading(shading)
+                        .with_intensity(sub_grid.intensity)
+                        .with_line_style(sub_grid.line_style),
                     ),
-                )));
+                ));
             } else {
-                child_commands.insert(MeshMaterial3d(simple_materials.add(
-                    SimpleLineMaterial::from_color(sub_grid.color, grid.alpha_mode),
-                )));
+                child_commands.insert(MeshMaterial3d(
+                    simple_materials.add(
+                        SimpleLineMaterial::from_color(sub_grid.color, grid.alpha_mode)
+                            .with_fade(fade, size - grid.spacing)
+                            .with_occlusion(occlusion)
+                            .with_antialiasing(antialiasing)
+                            .with_shading(shading)
+                            .with_intensity(sub_grid.intensity)
+                            .with_line_style(sub_grid.line_style),
+                    ),
+                ));
             }
             if let Some(render_layers) = render_layers {
                 child_commands.insert(render_layers.clone());
@@ -276,6 +705,7 @@ pub fn sub_grid_mesher(
     }
 }
 
+#[cfg(feature = "3d")]
 /// System for meshing grid axis, unless the grid is tracked (`Without<TrackedGrid>`)
 pub fn grid_axis_mesher(
     mut commands: Commands,
@@ -284,19 +714,46 @@ pub fn grid_axis_mesher(
             Entity,
             &Grid,
             Option<&GridAxis>,
+            Option<&GridFade>,
+            Option<&GridOcclusion>,
+            Option<&GridAntialiasing>,
+            Option<&GridShading>,
+            Option<&GridFill>,
             Option<&RenderLayers>,
             Option<&Children>,
         ),
         (
-            Or<(Changed<Grid>, Changed<GridAxis>, Changed<RenderLayers>)>,
+            Or<(
+                Changed<Grid>,
+                Changed<GridAxis>,
+                Changed<GridFade>,
+                Changed<GridOcclusion>,
+                Changed<GridAntialiasing>,
+                Changed<GridShading>,
+                Changed<GridFill>,
+                Changed<RenderLayers>,
+            )>,
             Without<TrackedGrid>,
+            Mesh3dBackend,
         ),
     >,
     query_children: Query<Entity, With<GridAxisChild>>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut simple_materials: ResMut<Assets<SimpleLineMaterial>>,
 ) {
-    for (entity, grid, axis, render_layers, children) in query_parent.iter() {
+    for (
+        entity,
+        grid,
+        axis,
+        fade,
+        occlusion,
+        antialiasing,
+        shading,
+        fill,
+        render_layers,
+        children,
+    ) in query_parent.iter()
+    {
         if let Some(children) = children {
             despawn_children_of_type(&mut commands, entity, children, &query_children);
         }
@@ -307,12 +764,17 @@ pub fn grid_axis_mesher(
             if let Some(axis) = axis {
                 let (used, unused) = axis.create_axis();
                 common_axis.extend(&unused);
-                for (alignment, color) in used {
-                    let mut mesh = Mesh::new(PrimitiveTopology::LineList, RenderAssetUsages::all());
-                    mesh.insert_attribute(
-                        Mesh::ATTRIBUTE_POSITION,
-                        GridAxis::create_single_axis(size, alignment).to_vec(),
-                    );
+                let mut axis_lines = used
+                    .into_iter()
+                    .flat_map(|(alignment, _)| {
+                        axis.create_axis_lines(size, grid.spacing, alignment)
+                    })
+                    .collect::<Vec<_>>();
+                if let Some(color) = axis.origin {
+                    axis_lines.push((GridAxis::create_origin_marker(grid.spacing), color));
+                }
+                for (vertices, color) in axis_lines {
+                    let mesh = line_mesh(vertices, antialiasing);
                     let mut commands = children.spawn((
                         GridAxisChild,
                         Mesh3d(meshes.add(mesh)),
@@ -320,8 +782,16 @@ pub fn grid_axis_mesher(
                         Transform::default(),
                         Visibility::default(),
                         MeshMaterial3d(
-                            simple_materials
-                                .add(SimpleLineMaterial::from_color(color, grid.alpha_mode)),
+                            simple_materials.add(
+                                SimpleLineMaterial::from_color(color, grid.alpha_mode)
+                                    .with_fade(fade, size - grid.spacing)
+                                    .with_occlusion(occlusion)
+                                    .with_antialiasing(antialiasing)
+                                    .with_shading(shading)
+                                    .with_intensity(axis.intensity)
+                                    .with_depth_bias(AXIS_DEPTH_BIAS)
+                                    .with_line_style(axis.line_style),
+                            ),
                         ),
                     ));
                     if let Some(render_layers) = render_layers {
@@ -332,13 +802,15 @@ pub fn grid_axis_mesher(
                 common_axis.extend(&GridAxis::default_axis());
             }
 
-            if !common_axis.is_empty() {
+            if !common_axis.is_empty()
+                && grid.style.is_lines()
+                && fill.is_none_or(GridFill::draws_lines)
+            {
                 let vertices = common_axis
                     .into_iter()
                     .flat_map(|alignment| GridAxis::create_single_axis(size, alignment))
                     .collect::<Vec<_>>();
-                let mut mesh = Mesh::new(PrimitiveTopology::LineList, RenderAssetUsages::all());
-                mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertices);
+                let mesh = line_mesh(vertices, antialiasing);
                 let mut commands = children.spawn((
                     GridAxisChild,
                     Mesh3d(meshes.add(mesh)),
@@ -346,8 +818,16 @@ pub fn grid_axis_mesher(
                     Transform::default(),
                     Visibility::default(),
                     MeshMaterial3d(
-                        simple_materials
-                            .add(SimpleLineMaterial::from_color(grid.color, grid.alpha_mode)),
+                        simple_materials.add(
+                            SimpleLineMaterial::from_color(grid.color, grid.alpha_mode)
+                                .with_fade(fade, size - grid.spacing)
+                                .with_occlusion(occlusion)
+                                .with_antialiasing(antialiasing)
+                                .with_shading(shading)
+                                .with_intensity(grid.intensity)
+                                .with_depth_bias(AXIS_DEPTH_BIAS)
+                                .with_line_style(grid.line_style),
+                        ),
                     ),
                 ));
                 if let Some(render_layers) = render_layers {
@@ -358,43 +838,287 @@ pub fn grid_axis_mesher(
     }
 }
 
+/// Creates the quad of a `GridFill`, covering a grid on its plane.
+/// Its UVs hold its local coordinates in cells, along the axis of the plane.
+#[cfg(feature = "3d")]
+fn grid_fill_mesh(grid: &Grid, alignment: &GridAlignment) -> Mesh {
+    let size = grid.count as f32 * grid.spacing;
+    let [first, second] = alignment.plane_axis().map(|axis| axis.to_axis_vec3());
+    let normal = alignment.to_axis_vec3();
+    let corners = [
+        Vec2::new(-size, -size),
+        Vec2::new(size, -size),
+        Vec2::new(size, size),
+        Vec2::new(-size, size),
+    ];
+    let positions = corners
+        .map(|corner| first * corner.x + second * corner.y + normal * GRID_FILL_VERTICAL_OFFSET);
+    let uvs = corners.map(|corner| (corner / grid.spacing).to_array());
+    Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::all())
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions.to_vec())
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, vec![normal; 4])
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs.to_vec())
+        .with_inserted_indices(Indices::U32(vec![0, 1, 2, 0, 2, 3]))
+}
+
+#[cfg(feature = "3d")]
+/// System for meshing the `GridFill` of grids.
+/// The fill of tracked grids is counted from the world's origin, and fades out along with their lines.
+pub fn grid_fill_mesher(
+    mut commands: Commands,
+    query_parent: Query<
+        (
+            Entity,
+            &Grid,
+            &GridFill,
+            Option<&TrackedGrid>,
+            Option<&TrackedGridCount>,
+            Option<&GridFade>,
+            Option<&GridOcclusion>,
+            Option<&GridShading>,
+            Option<&RenderLayers>,
+            Option<&Children>,
+        ),
+        (
+            Or<(
+                Changed<Grid>,
+                Changed<GridFill>,
+                Changed<TrackedGrid>,
+                Changed<TrackedGridCount>,
+                Changed<GridFade>,
+                Changed<GridOcclusion>,
+                Changed<GridShading>,
+                Changed<RenderLayers>,
+            )>,
+            Mesh3dBackend,
+        ),
+    >,
+    query_children: Query<Entity, With<GridFillChild>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut checkerboard_materials: ResMut<Assets<CheckerboardMaterial>>,
+) {
+    for (entity, grid, fill, tracked, count, fade, occlusion, shading, render_layers, children) in
+        query_parent.iter()
+    {
+        if let Some(children) = children {
+            despawn_children_of_type(&mut commands, entity, children, &query_children);
+        }
+        let GridFill::Checkerboard { colors, .. } = *fill;
+
+        let grid = &tracked_grid_with_count(grid, count);
+        let radius = grid.count as f32 * grid.spacing - grid.spacing;
+        let alignment = grid_plane_alignment(tracked, false);
+        let mut material = CheckerboardMaterial::new(colors, alignment, grid.spacing)
+            .with_occlusion(occlusion)
+            .with_shading(shading)
+            .with_depth_bias(GRID_FILL_DEPTH_BIAS);
+        material = match tracked {
+            // Tracked grids always fade, like the `ClippedLineMaterial`
+            Some(_) => material
+                .world_locked()
+                .with_fade(Some(&fade.copied().unwrap_or_default()), radius),
+            None => material.with_fade(fade, radius),
+        };
+
+        commands.entity(entity).with_children(|children| {
+            let mut commands = children.spawn((
+                GridFillChild,
+                Mesh3d(meshes.add(grid_fill_mesh(grid, &alignment))),
+                MeshMaterial3d(checkerboard_materials.add(material)),
+                NotShadowCaster,
+                Transform::default(),
+                Visibility::default(),
+            ));
+            if let Some(render_layers) = render_layers {
+                commands.insert(render_layers.clone());
+            }
+        });
+    }
+}
+
+#[cfg(feature = "3d")]
+/// System centering the fade of untracked grids and of their fill on their origin,
+/// when their `GridFade` uses `FadeCenter::GridOrigin`.
+/// Materials are only modified when the grid's origin moves.
+pub fn untracked_grid_fade_center_updater(
+    query_parent: Query<(&GridFade, &GlobalTransform, &Children), Without<TrackedGrid>>,
+    query_children: Query<&MeshMaterial3d<SimpleLineMaterial>>,
+    query_fill_children: Query<&MeshMaterial3d<CheckerboardMaterial>>,
+    mut simple_materials: ResMut<Assets<SimpleLineMaterial>>,
+    mut checkerboard_materials: ResMut<Assets<CheckerboardMaterial>>,
+) {
+    for (fade, transform, children) in query_parent.iter() {
+        let center = (fade.center == FadeCenter::GridOrigin).then(|| transform.translation());
+        for material in query_children.iter_many(children) {
+            let outdated = simple_materials
+                .get(&material.0)
+                .is_some_and(|material| material.fade_center != center);
+            if let Some(material) = simple_materials.get_mut(&material.0).filter(|_| outdated) {
+                material.fade_center = center;
+            }
+        }
+        for material in query_fill_children.iter_many(children) {
+            let outdated = checkerboard_materials
+                .get(&material.0)
+                .is_some_and(|material| material.fade_center != center);
+            if let Some(material) = checkerboard_materials
+                .get_mut(&material.0)
+                .filter(|_| outdated)
+            {
+                material.fade_center = center;
+            }
+        }
+    }
+}
+
+/// Returns the point a tracked grid is centered on.
+/// For orthographic projections, it is where the view direction meets the grid's plane,
+/// otherwise it is the tracked entity's translation.
+pub(crate) fn tracked_grid_center(
+    tracked: &TrackedGrid,
+    tracked_transform: &GlobalTransform,
+    projection: Option<&Projection>,
+) -> Vec3 {
+    let translation = tracked_transform.translation();
+    let Some(Projection::Orthographic(_)) = projection else {
+        return translation;
+    };
+    let normal = tracked.alignment.to_axis_vec3();
+    let forward = tracked_transform.forward();
+    let facing = forward.dot(normal);
+    if facing.abs() <= f32::EPSILON {
+        return translation;
+    }
+    // The plane may be behind the view's origin, an orthographic projection sees it nonetheless
+    translation + forward * ((normal * tracked.offset - translation).dot(normal) / facing)
+}
+
+/// Returns the line count a tracked grid needs to cover the visible area of an orthographic projection.
+/// The count is rounded up to a power of two to avoid remeshing the grid on every zoom step.
+fn orthographic_grid_count(
+    grid: &Grid,
+    tracked: &TrackedGrid,
+    tracked_transform: &GlobalTransform,
+    projection: &OrthographicProjection,
+) -> usize {
+    let area = projection.area;
+    let extents = area.min.abs().max(area.max.abs());
+    // The visible area stretches across the plane when it is viewed at an angle
+    let facing = tracked_transform
+        .forward()
+        .dot(tracked.alignment.to_axis_vec3())
+        .abs()
+        .max(0.1_f32);
+    let visible_radius = extents.length() / facing;
+    // Account for the clipping radius being one spacing short of the mesh, and for the snapping offset
+    let count = (visible_radius / grid.spacing).ceil() as usize + 2;
+    count.next_power_of_two().max(grid.count)
+}
+
+/// Moves a tracked grid along with a tracked entity.
+/// Returns the line count the grid needs to cover the tracked entity's view.
+fn track_grid(
+    grid_transform: &mut Transform,
+    grid: &Grid,
+    tracked: &TrackedGrid,
+    tracked_transform: &GlobalTransform,
+    projection: Option<&Projection>,
+) -> usize {
+    let alignment = tracked.alignment.to_inverted_axis_vec3();
+    let translation = tracked_grid_center(tracked, tracked_transform, projection) * alignment;
+    let offset = tracked.alignment.to_axis_vec3() * tracked.offset;
+    grid_transform.translation = (translation / grid.spacing).floor() * grid.spacing + offset;
+
+    match projection {
+        Some(Projection::Orthographic(projection)) => {
+            orthographic_grid_count(grid, tracked, tracked_transform, projection)
+        }
+        _ => grid.count,
+    }
+}
+
+/// Updates the `TrackedGridCount` of a grid, only inserting it when it differs from `Grid::count`
+fn update_tracked_grid_count(
+    commands: &mut Commands,
+    entity: Entity,
+    grid: &Grid,
+    count: Option<Mut<TrackedGridCount>>,
+    new_count: usize,
+) {
+    match count {
+        Some(mut count) => {
+            count.set_if_neq(TrackedGridCount(new_count));
+        }
+        None if new_count != grid.count => {
+            commands.entity(entity).insert(TrackedGridCount(new_count));
+        }
+        None => {}
+    }
+}
+
 /// System which moves tracked grids along with the defined component `T`.
 /// Does nothing if the query's `.get_single()` fails.
 pub fn tracked_grid_updater<T: Component>(
-    mut floor_grid_query: Query<(&mut Transform, &Grid, &TrackedGrid)>,
-    tracked_transform_query: Query<&GlobalTransform, (With<T>, Without<TrackedGrid>)>,
+    mut commands: Commands,
+    mut floor_grid_query: Query<(
+        Entity,
+        &mut Transform,
+        &Grid,
+        &TrackedGrid,
+        Option<&mut TrackedGridCount>,
+    )>,
+    tracked_transform_query: Query<
+        (&GlobalTransform, Option<&Projection>),
+        (With<T>, Without<TrackedGrid>, TrackedEntityFilter),
+    >,
 ) {
-    let Ok(tracked_transform) = tracked_transform_query.single() else {
+    let Ok((tracked_transform, projection)) = tracked_transform_query.single() else {
         return;
     };
-    for (mut grid_transform, grid, tracked) in floor_grid_query.iter_mut() {
+    for (entity, mut grid_transform, grid, tracked, count) in floor_grid_query.iter_mut() {
         if tracked.tracking_override.is_some() {
             continue;
         };
-        let alignment = tracked.alignment.to_inverted_axis_vec3();
-        let translation = tracked_transform.translation() * alignment;
-        let offset = tracked.alignment.to_axis_vec3() * tracked.offset;
-        grid_transform.translation = (translation / grid.spacing).floor() * grid.spacing + offset;
+        let new_count = track_grid(
+            &mut grid_transform,
+            grid,
+            tracked,
+            tracked_transform,
+            projection,
+        );
+        update_tracked_grid_count(&mut commands, entity, grid, count, new_count);
     }
 }
 
 /// System which moves tracked grids along with `tracking_override` entity of a `TrackedGrid`.
 /// Does nothing if the tracking override entity does not have a `GlobalTransform`.
 pub fn custom_tracked_grid_updater(
-    mut custom_tracked_grid_query: Query<(&mut Transform, &Grid, &TrackedGrid)>,
-    tracked_transform_query: Query<&GlobalTransform>,
+    mut commands: Commands,
+    mut custom_tracked_grid_query: Query<(
+        Entity,
+        &mut Transform,
+        &Grid,
+        &TrackedGrid,
+        Option<&mut TrackedGridCount>,
+    )>,
+    tracked_transform_query: Query<(&GlobalTransform, Option<&Projection>)>,
 ) {
-    for (mut grid_transform, grid, tracked) in custom_tracked_grid_query.iter_mut() {
-        let Some(entity) = tracked.tracking_override else {
+    for (entity, mut grid_transform, grid, tracked, count) in custom_tracked_grid_query.iter_mut() {
+        let Some(tracked_entity) = tracked.tracking_override else {
             continue;
         };
-        let Ok(tracked_transform) = tracked_transform_query.get(entity) else {
+        let Ok((tracked_transform, projection)) = tracked_transform_query.get(tracked_entity)
+        else {
             continue;
         };
-        let alignment = tracked.alignment.to_inverted_axis_vec3();
-        let translation = tracked_transform.translation() * alignment;
-        let offset = tracked.alignment.to_axis_vec3() * tracked.offset;
-        grid_transform.translation = (translation / grid.spacing).floor() * grid.spacing + offset;
+        let new_count = track_grid(
+            &mut grid_transform,
+            grid,
+            tracked,
+            tracked_transform,
+            projection,
+        );
+        update_tracked_grid_count(&mut commands, entity, grid, count, new_count);
     }
 }
 
@@ -422,3 +1146,20 @@ pub fn despawn_children_upon_removal<RemovedParent: Component, ChildMarker: Comp
         commands.entity(*entity).despawn();
     }
 }
+
+/// Marks a component as changed upon the removal of another component from the same entity,
+/// so that the systems meshing the entity upon changes reflect the removal
+#[cfg(any(feature = "3d", feature = "2d"))]
+pub fn remesh_upon_removal<
+    Removed: Component,
+    Meshed: Component<Mutability = bevy::ecs::component::Mutable>,
+>(
+    mut removed: RemovedComponents<Removed>,
+    mut query: Query<&mut Meshed>,
+) {
+    for entity in removed.read() {
+        if let Ok(mut meshed) = query.get_mut(entity) {
+            meshed.set_changed();
+        }
+    }
+}
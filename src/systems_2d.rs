@@ -0,0 +1,369 @@
+#![allow(clippy::type_complexity)]
+
+use bevy::asset::RenderAssetUsages;
+use bevy::camera::visibility::RenderLayers;
+use bevy::prelude::*;
+use bevy::render::render_resource::PrimitiveTopology;
+
+use crate::*;
+
+/// Alignment of every 2D grid, placing it on the XY plane
+const ALIGNMENT_2D: GridAlignment = GridAlignment::Z;
+
+/// Creates the mesh of a 2D grid's markers, drawn filled as triangles when the `GridStyle` is filled, and as lines otherwise
+fn marker_mesh_2d(vertices: Vec<Vec3>, style: GridStyle) -> Mesh {
+    let topology = if style.is_filled() {
+        PrimitiveTopology::TriangleList
+    } else {
+        PrimitiveTopology::LineList
+    };
+    Mesh::new(topology, RenderAssetUsages::all())
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, vertices)
+}
+
+/// Spawns a default 2D floor grid, tracked along with the camera, behind sprites
+pub fn spawn_floor_grid_2d(mut commands: Commands) {
+    commands.spawn((
+        Grid {
+            spacing: 100.0_f32,
+            count: 16,
+            ..default()
+        },
+        SubGrid {
+            count: 4,
+            ..default()
+        },
+        GridAxis::new_rgb(),
+        TrackedGrid {
+            alignment: ALIGNMENT_2D,
+            offset: Grid2d::DEFAULT_Z,
+            ..default()
+        },
+        Grid2d,
+        Transform::from_xyz(0.0_f32, 0.0_f32, Grid2d::DEFAULT_Z),
+        Visibility::default(),
+    ));
+}
+
+/// System for meshing 2D (`With<Grid2d>`) grids, tracked or not
+pub fn main_grid_mesher_2d(
+    mut commands: Commands,
+    query_parent: Query<
+        (
+            Entity,
+            &Grid,
+            Option<&TrackedGrid>,
+            Option<&TrackedGridCount>,
+            Option<&GridAxis>,
+            Option<&GridFade>,
+            Option<&RenderLayers>,
+            Option<&Children>,
+        ),
+        (
+            Or<(
+                Changed<Grid>,
+                Changed<TrackedGrid>,
+                Changed<TrackedGridCount>,
+                Changed<GridAxis>,
+                Changed<GridFade>,
+                Changed<RenderLayers>,
+            )>,
+            With<Grid2d>,
+            GizmoBackend,
+        ),
+    >,
+    query_children: Query<Entity, With<GridChild>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut clipped_materials: ResMut<Assets<ClippedLineMaterial2d>>,
+    mut simple_materials: ResMut<Assets<SimpleLineMaterial2d>>,
+) {
+    for (entity, grid, tracked, count, axis, fade, render_layers, children) in query_parent.iter() {
+        let grid = &tracked_grid_with_count(grid, count);
+        let (mut vertices, size) = main_grid_vertices_and_size(grid, &ALIGNMENT_2D);
+        let mut center_lines = Vec::new();
+        if tracked.is_some() {
+            // Tracked axis are colored by the material, based on their position
+            for in_plane in ALIGNMENT_2D.plane_axis() {
+                if grid.style.is_lines()
+                    || axis.is_some_and(|axis| axis.get_by_alignment(&in_plane).is_some())
+                {
+                    center_lines.extend(GridAxis::create_single_axis(size, in_plane));
+                }
+            }
+        }
+        // Filled markers are triangles, meshed apart from the center lines
+        let mut grid_meshes = if grid.style.is_filled() {
+            vec![
+                marker_mesh_2d(vertices, grid.style),
+                marker_mesh_2d(center_lines, GridStyle::Lines),
+            ]
+        } else {
+            vertices.extend(center_lines);
+            vec![marker_mesh_2d(vertices, grid.style)]
+        };
+        grid_meshes.retain(|mesh| mesh.count_vertices() > 0);
+
+        if let Some(children) = children {
+            despawn_children_of_type(&mut commands, entity, children, &query_children);
+        }
+        let alpha_mode = alpha_mode_2d(grid.alpha_mode);
+        let clipped_material = tracked.is_some().then(|| {
+            clipped_materials.add(
+                ClippedLineMaterial2d::new(grid.color, alpha_mode, size - grid.spacing, axis)
+                    .with_fade(fade),
+            )
+        });
+        let simple_material = tracked.is_none().then(|| {
+            simple_materials.add(
+                SimpleLineMaterial2d::from_color(grid.color, alpha_mode)
+                    .with_fade(fade, size - grid.spacing),
+            )
+        });
+        commands.entity(entity).with_children(|children| {
+            for mesh in grid_meshes {
+                let mut child_commands = children.spawn((
+                    GridChild,
+                    Mesh2d(meshes.add(mesh)),
+                    Transform::default(),
+                    Visibility::default(),
+                ));
+                if let Some(material) = &clipped_material {
+                    child_commands.insert(MeshMaterial2d(material.clone()));
+                }
+                if let Some(material) = &simple_material {
+                    child_commands.insert(MeshMaterial2d(material.clone()));
+                }
+                if let Some(render_layers) = render_layers {
+                    child_commands.insert(render_layers.clone());
+                }
+            }
+        });
+
+        // Tracked axis markers stay at origin, but inherit visibility through a parent entity
+        let (Some(tracked), Some(axis)) = (tracked, axis) else {
+            continue;
+        };
+        let markers =
+            tracked_axis_markers(axis, size - grid.spacing, grid.spacing, ALIGNMENT_2D, fade);
+        if markers.is_empty() {
+            continue;
+        }
+        let transform = Transform::from_xyz(0.0_f32, 0.0_f32, tracked.offset);
+        commands.entity(entity).with_children(|children| {
+            children
+                .spawn((GridChild, GlobalTransform::default(), Visibility::default()))
+                .with_children(|markers_parent| {
+                    for (vertices, color) in markers {
+                        let mut mesh =
+                            Mesh::new(PrimitiveTopology::LineList, RenderAssetUsages::all());
+                        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertices);
+                        let mut marker_commands = markers_parent.spawn((
+                            GridChild,
+                            Mesh2d(meshes.add(mesh)),
+                            transform,
+                            GlobalTransform::from(transform),
+                            Visibility::default(),
+                            MeshMaterial2d(
+                                clipped_materials.add(
+                                    ClippedLineMaterial2d::new(
+                                        color,
+                                        alpha_mode_2d(grid.alpha_mode),
+                                        size - grid.spacing,
+                                        None,
+                                    )
+                                    .with_fade(fade),
+                                ),
+                            ),
+                        ));
+                        if let Some(render_layers) = render_layers {
+                            marker_commands.insert(render_layers.clone());
+                        }
+                    }
+                });
+        });
+    }
+}
+
+/// System for meshing 2D (`With<Grid2d>`) sub-grids
+pub fn sub_grid_mesher_2d(
+    mut commands: Commands,
+    query_parent: Query<
+        (
+            Entity,
+            &Grid,
+            &SubGrid,
+            Option<&TrackedGrid>,
+            Option<&TrackedGridCount>,
+            Option<&GridFade>,
+            Option<&RenderLayers>,
+            Option<&Children>,
+        ),
+        (
+            Or<(
+                Changed<Grid>,
+                Changed<SubGrid>,
+                Changed<TrackedGrid>,
+                Changed<TrackedGridCount>,
+                Changed<GridFade>,
+                Changed<RenderLayers>,
+            )>,
+            With<Grid2d>,
+            GizmoBackend,
+        ),
+    >,
+    query_children: Query<Entity, With<SubGridChild>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut clipped_materials: ResMut<Assets<ClippedLineMaterial2d>>,
+    mut simple_materials: ResMut<Assets<SimpleLineMaterial2d>>,
+) {
+    for (entity, grid, sub_grid, tracked, count, fade, render_layers, children) in
+        query_parent.iter()
+    {
+        let grid = &tracked_grid_with_count(grid, count);
+        let size = grid.count as f32 * grid.spacing;
+        let vertices = sub_grid_vertices(grid, sub_grid, &ALIGNMENT_2D);
+        let mesh = marker_mesh_2d(vertices, grid.style);
+
+        if let Some(children) = children {
+            despawn_children_of_type(&mut commands, entity, children, &query_children);
+        }
+        commands.entity(entity).with_children(|children| {
+            let mut child_commands = children.spawn((
+                SubGridChild,
+                Mesh2d(meshes.add(mesh)),
+                Transform::from_translation(
+                    ALIGNMENT_2D.shift_vec3(-Vec3::Y * SUB_GRID_VERTICAL_OFFSET),
+                ),
+                Visibility::default(),
+            ));
+            let alpha_mode = alpha_mode_2d(grid.alpha_mode);
+            if tracked.is_some() {
+                child_commands.insert(MeshMaterial2d(
+                    clipped_materials.add(
+                        ClippedLineMaterial2d::new(
+                            sub_grid.color,
+                            alpha_mode,
+                            size - grid.spacing,
+                            None,
+                        )
+                        .with_fade(fade),
+                    ),
+                ));
+            } else {
+                child_commands.insert(MeshMaterial2d(
+                    simple_materials.add(
+                        SimpleLineMaterial2d::from_color(sub_grid.color, alpha_mode)
+                            .with_fade(fade, size - grid.spacing),
+                    ),
+                ));
+            }
+            if let Some(render_layers) = render_layers {
+                child_commands.insert(render_layers.clone());
+            }
+        });
+    }
+}
+
+/// System for meshing 2D (`With<Grid2d>`) grid axis, unless the grid is tracked (`Without<TrackedGrid>`)
+pub fn grid_axis_mesher_2d(
+    mut commands: Commands,
+    query_parent: Query<
+        (
+            Entity,
+            &Grid,
+            Option<&GridAxis>,
+            Option<&GridFade>,
+            Option<&RenderLayers>,
+            Option<&Children>,
+        ),
+        (
+            Or<(
+                Changed<Grid>,
+                Changed<GridAxis>,
+                Changed<GridFade>,
+                Changed<RenderLayers>,
+            )>,
+            With<Grid2d>,
+            GizmoBackend,
+            Without<TrackedGrid>,
+        ),
+    >,
+    query_children: Query<Entity, With<GridAxisChild>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut simple_materials: ResMut<Assets<SimpleLineMaterial2d>>,
+) {
+    for (entity, grid, axis, fade, render_layers, children) in query_parent.iter() {
+        if let Some(children) = children {
+            despawn_children_of_type(&mut commands, entity, children, &query_children);
+        }
+
+        let size = grid.count as f32 * grid.spacing;
+        let alpha_mode = alpha_mode_2d(grid.alpha_mode);
+        // Axis without a configured color are merged into a single mesh using the grid's color
+        let mut common_axis = Vec::<GridAlignment>::new();
+        let mut axis_meshes = Vec::<(Vec<Vec3>, Color)>::new();
+        for alignment in [GridAlignment::X, GridAlignment::Y] {
+            match axis.filter(|axis| axis.get_by_alignment(&alignment).is_some()) {
+                Some(axis) => {
+                    axis_meshes.extend(axis.create_axis_lines(size, grid.spacing, alignment));
+                }
+                None => common_axis.push(alignment),
+            }
+        }
+        if let Some(color) = axis.and_then(|axis| axis.origin) {
+            axis_meshes.push((GridAxis::create_origin_marker(grid.spacing), color));
+        }
+        if !common_axis.is_empty() && grid.style.is_lines() {
+            let vertices = common_axis
+                .into_iter()
+                .flat_map(|alignment| GridAxis::create_single_axis(size, alignment))
+                .collect::<Vec<_>>();
+            axis_meshes.push((vertices, grid.color));
+        }
+
+        commands.entity(entity).with_children(|children| {
+            for (vertices, color) in axis_meshes {
+                let mut mesh = Mesh::new(PrimitiveTopology::LineList, RenderAssetUsages::all());
+                mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertices);
+                let mut commands = children.spawn((
+                    GridAxisChild,
+                    Mesh2d(meshes.add(mesh)),
+                    Transform::default(),
+                    Visibility::default(),
+                    MeshMaterial2d(
+                        simple_materials.add(
+                            SimpleLineMaterial2d::from_color(color, alpha_mode)
+                                .with_fade(fade, size - grid.spacing),
+                        ),
+                    ),
+                ));
+                if let Some(render_layers) = render_layers {
+                    commands.insert(render_layers.clone());
+                }
+            }
+        });
+    }
+}
+
+/// System centering the fade of untracked 2D grids on their origin, when their `GridFade` uses `FadeCenter::GridOrigin`.
+/// Materials are only modified when the grid's origin moves.
+pub fn untracked_grid_fade_center_updater_2d(
+    query_parent: Query<
+        (&GridFade, &GlobalTransform, &Children),
+        (With<Grid2d>, Without<TrackedGrid>),
+    >,
+    query_children: Query<&MeshMaterial2d<SimpleLineMaterial2d>>,
+    mut simple_materials: ResMut<Assets<SimpleLineMaterial2d>>,
+) {
+    for (fade, transform, children) in query_parent.iter() {
+        let center = (fade.center == FadeCenter::GridOrigin).then(|| transform.translation());
+        for material in query_children.iter_many(children) {
+            let outdated = simple_materials
+                .get(&material.0)
+                .is_some_and(|material| material.fade_center != center);
+            if let Some(material) = simple_materials.get_mut(&material.0).filter(|_| outdated) {
+                material.fade_center = center;
+            }
+        }
+    }
+}
@@ -0,0 +1,317 @@
+#![allow(clippy::type_complexity)]
+
+#[cfg(feature = "3d")]
+use bevy::asset::RenderAssetUsages;
+use bevy::camera::primitives::Aabb;
+#[cfg(feature = "3d")]
+use bevy::camera::visibility::RenderLayers;
+use bevy::color::palettes::tailwind;
+#[cfg(feature = "3d")]
+use bevy::light::NotShadowCaster;
+use bevy::prelude::*;
+#[cfg(feature = "3d")]
+use bevy::render::render_resource::PrimitiveTopology;
+
+use crate::*;
+
+/// Bounds of a `GridVolume`, in the local space of its entity
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
+pub enum VolumeBounds {
+    /// The entity's `Aabb`, such as the bounds computed for its mesh.
+    /// The volume follows the `Aabb` whenever it changes.
+    #[default]
+    Aabb,
+    /// Explicit bounds
+    MinMax { min: Vec3, max: Vec3 },
+}
+
+/// Fills bounds with a 3D lattice of lines, along with the edges of the bounding box in a distinct color.
+/// Spawn it on an entity with an `Aabb`, such as a mesh, or with explicit `VolumeBounds::MinMax`.
+///
+/// The line count along each axis is derived from `spacing`, and the spacing is then adjusted
+/// for the lattice to fit the bounds exactly.
+/// Volumes are rendered as meshes, or through `Gizmos` when marked with `GizmoGrid`.
+#[derive(Component, Clone, Debug)]
+pub struct GridVolume {
+    /// Bounds filled by the lattice
+    pub bounds: VolumeBounds,
+    /// Desired spacing between lines
+    pub spacing: f32,
+    /// Color of the lattice's lines
+    pub color: Color,
+    /// Color of the bounding box's edges
+    pub edge_color: Color,
+    /// Alpha mode for the lattice and the edges
+    pub alpha_mode: AlphaMode,
+}
+
+impl GridVolume {
+    pub const DEFAULT_EDGE_SRGBA: Srgba = tailwind::ORANGE_400;
+
+    /// Creates a volume fitting the entity's `Aabb`
+    pub fn from_aabb(spacing: f32) -> Self {
+        Self {
+            spacing,
+            ..default()
+        }
+    }
+
+    /// Creates a volume fitting explicit bounds
+    pub fn from_min_max(min: Vec3, max: Vec3, spacing: f32) -> Self {
+        Self {
+            bounds: VolumeBounds::MinMax { min, max },
+            spacing,
+            ..default()
+        }
+    }
+
+    /// Returns the minimum and maximum corners of the volume, if its bounds are available
+    pub fn min_max(&self, aabb: Option<&Aabb>) -> Option<(Vec3, Vec3)> {
+        match self.bounds {
+            VolumeBounds::Aabb => aabb.map(|aabb| (aabb.min().into(), aabb.max().into())),
+            VolumeBounds::MinMax { min, max } => Some((min.min(max), min.max(max))),
+        }
+    }
+
+    /// Returns the line count along each axis, fitting the extents of the volume with lines spaced by about `spacing`
+    pub fn counts(&self, min: Vec3, max: Vec3) -> UVec3 {
+        let extents = max - min;
+        if self.spacing <= 0.0_f32 {
+            return UVec3::ZERO;
+        }
+        // Flat axis have no line along them
+        Vec3::select(
+            extents.cmpgt(Vec3::ZERO),
+            (extents / self.spacing).round().max(Vec3::ONE),
+            Vec3::ZERO,
+        )
+        .as_uvec3()
+    }
+
+    /// Returns the vertices of the lattice's lines, and of the bounding box's edges, `(lattice, edges)`
+    pub fn line_vertices(&self, min: Vec3, max: Vec3) -> (Vec<Vec3>, Vec<Vec3>) {
+        let counts = self.counts(min, max);
+        let extents = max - min;
+        let mut lattice = Vec::new();
+        let mut edges = Vec::new();
+        for axis in [GridAlignment::X, GridAlignment::Y, GridAlignment::Z] {
+            let along = axis.to_axis_vec3();
+            if extents.dot(along) <= 0.0_f32 {
+                continue;
+            }
+            let [first, second] = axis.plane_axis();
+            let first_count = counts.dot(first.to_axis_vec3().as_uvec3());
+            let second_count = counts.dot(second.to_axis_vec3().as_uvec3());
+            for i in 0..=first_count {
+                for j in 0..=second_count {
+                    // Positions of the line within the plane across the axis, from `0.0` to `1.0`
+                    let t = Vec2::new(
+                        i as f32 / first_count.max(1) as f32,
+                        j as f32 / second_count.max(1) as f32,
+                    );
+                    let offset =
+                        min + (first.to_axis_vec3() * t.x + second.to_axis_vec3() * t.y) * extents;
+                    let start = offset * (Vec3::ONE - along) + min * along;
+                    let end = offset * (Vec3::ONE - along) + max * along;
+                    let is_edge = (i == 0 || i == first_count) && (j == 0 || j == second_count);
+                    if is_edge {
+                        edges.extend([start, end]);
+                    } else {
+                        lattice.extend([start, end]);
+                    }
+                }
+            }
+        }
+        (lattice, edges)
+    }
+
+    /// Splits the vertices of the lattice into line lists sharing a color,
+    /// lines across the Y axis being colored by their height with a `GridHeightColor`
+    pub fn colored_lattice(
+        &self,
+        lattice: Vec<Vec3>,
+        height_color: Option<&GridHeightColor>,
+    ) -> Vec<(Vec<Vec3>, Color)> {
+        let Some(height_color) = height_color else {
+            return vec![(lattice, self.color)];
+        };
+        let mut lists: Vec<(Vec<Vec3>, Color)> = Vec::new();
+        for line in lattice.chunks_exact(2) {
+            let color = if line[0].y == line[1].y {
+                height_color.tint(self.color, line[0].y)
+            } else {
+                self.color
+            };
+            match lists
+                .iter_mut()
+                .find(|(_, list_color)| *list_color == color)
+            {
+                Some((vertices, _)) => vertices.extend(line),
+                None => lists.push((line.to_vec(), color)),
+            }
+        }
+        lists
+    }
+}
+
+impl Default for GridVolume {
+    fn default() -> Self {
+        Self {
+            bounds: VolumeBounds::default(),
+            spacing: 1.0_f32,
+            color: Color::Srgba(Grid::DEFAULT_SRGBA.with_alpha(Grid::DEFAULT_ALPHA)),
+            edge_color: Color::Srgba(Self::DEFAULT_EDGE_SRGBA),
+            alpha_mode: AlphaMode::Blend,
+        }
+    }
+}
+
+/// Marker component to determine children spawned by a `GridVolume`
+#[derive(Component)]
+pub struct GridVolumeChild;
+
+/// System for meshing grid volumes, whenever they or the `Aabb` they fit change
+#[cfg(feature = "3d")]
+pub fn grid_volume_mesher(
+    mut commands: Commands,
+    query_parent: Query<
+        (
+            Entity,
+            &GridVolume,
+            Option<&Aabb>,
+            Option<&GridHeightColor>,
+            Option<&RenderLayers>,
+            Option<&Children>,
+        ),
+        (
+            Or<(
+                Changed<GridVolume>,
+                Changed<Aabb>,
+                Changed<GridHeightColor>,
+                Changed<RenderLayers>,
+            )>,
+            GizmoBackend,
+        ),
+    >,
+    query_children: Query<Entity, With<GridVolumeChild>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut simple_materials: ResMut<Assets<SimpleLineMaterial>>,
+) {
+    for (entity, volume, aabb, height_color, render_layers, children) in query_parent.iter() {
+        if let Some(children) = children {
+            despawn_children_of_type(&mut commands, entity, children, &query_children);
+        }
+        let Some((min, max)) = volume.min_max(aabb) else {
+            continue;
+        };
+
+        let (lattice, edges) = volume.line_vertices(min, max);
+        let mut lists = volume.colored_lattice(lattice, height_color);
+        lists.push((edges, volume.edge_color));
+        commands.entity(entity).with_children(|children| {
+            for (vertices, color) in lists {
+                if vertices.is_empty() {
+                    continue;
+                }
+                let mut mesh = Mesh::new(PrimitiveTopology::LineList, RenderAssetUsages::all());
+                mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertices);
+                let mut child_commands = children.spawn((
+                    GridVolumeChild,
+                    Mesh3d(meshes.add(mesh)),
+                    NotShadowCaster,
+                    Transform::default(),
+                    Visibility::default(),
+                    MeshMaterial3d(
+                        simple_materials
+                            .add(SimpleLineMaterial::from_color(color, volume.alpha_mode)),
+                    ),
+                ));
+                if let Some(render_layers) = render_layers {
+                    child_commands.insert(render_layers.clone());
+                }
+            }
+        });
+    }
+}
+
+/// System drawing grid volumes marked with `GizmoGrid`
+#[cfg(feature = "gizmos")]
+pub fn draw_gizmo_grid_volumes(
+    mut gizmos: Gizmos<DebugGridGizmos>,
+    query: Query<
+        (
+            &GridVolume,
+            Option<&Aabb>,
+            Option<&GridHeightColor>,
+            &GlobalTransform,
+            &InheritedVisibility,
+        ),
+        With<GizmoGrid>,
+    >,
+) {
+    for (volume, aabb, height_color, transform, visibility) in query.iter() {
+        if !visibility.get() {
+            continue;
+        }
+        let Some((min, max)) = volume.min_max(aabb) else {
+            continue;
+        };
+        let (lattice, edges) = volume.line_vertices(min, max);
+        for (vertices, color) in volume.colored_lattice(lattice, height_color) {
+            draw_line_list(&mut gizmos, transform, &vertices, color);
+        }
+        draw_line_list(&mut gizmos, transform, &edges, volume.edge_color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_fit_extents() {
+        let volume = GridVolume::from_aabb(1.0_f32);
+        assert_eq!(
+            volume.counts(Vec3::ZERO, Vec3::new(2.4_f32, 0.2_f32, 3.6_f32)),
+            UVec3::new(2, 1, 4)
+        );
+        // Flat axis have no line along them
+        assert_eq!(
+            volume.counts(Vec3::ZERO, Vec3::new(2.0_f32, 0.0_f32, 1.0_f32)),
+            UVec3::new(2, 0, 1)
+        );
+        assert_eq!(
+            GridVolume::from_aabb(0.0_f32).counts(Vec3::ZERO, Vec3::ONE),
+            UVec3::ZERO
+        );
+    }
+
+    #[test]
+    fn line_vertices_split_edges_from_lattice() {
+        let volume = GridVolume::from_aabb(1.0_f32);
+        let (min, max) = (Vec3::ZERO, Vec3::new(2.0_f32, 1.0_f32, 1.0_f32));
+        let (lattice, edges) = volume.line_vertices(min, max);
+        // The 12 edges of the box, and the 2 inner lines along both Y and Z at the middle of X
+        assert_eq!(edges.len(), 24);
+        assert_eq!(lattice.len(), 8);
+        assert!(lattice.iter().all(|vertex| vertex.x == 1.0_f32));
+        assert!(lattice
+            .iter()
+            .chain(&edges)
+            .all(|vertex| vertex.cmpge(min).all() && vertex.cmple(max).all()));
+    }
+
+    #[test]
+    fn line_vertices_of_flat_volume_stay_on_plane() {
+        let volume = GridVolume::from_aabb(1.0_f32);
+        let (lattice, edges) =
+            volume.line_vertices(Vec3::ZERO, Vec3::new(2.0_f32, 0.0_f32, 2.0_f32));
+        // A 2x2 grid, with 4 edges and the 2 center lines
+        assert_eq!(edges.len(), 8);
+        assert_eq!(lattice.len(), 4);
+        assert!(lattice
+            .iter()
+            .chain(&edges)
+            .all(|vertex| vertex.y == 0.0_f32));
+    }
+}